@@ -1,6 +1,7 @@
 fn main() {
     duchess_build_rs::DuchessBuildRs::new()
         .with_src_path("src/".into())
+        .with_java_src_path("java/".into())
         .execute()
         .unwrap();
 }
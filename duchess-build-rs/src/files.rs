@@ -10,12 +10,23 @@ pub(crate) struct File {
 }
 
 pub fn rs_files(path: impl AsRef<Path>) -> impl Iterator<Item = anyhow::Result<File>> {
+    files_with_extension(path, "rs")
+}
+
+pub fn java_files(path: impl AsRef<Path>) -> impl Iterator<Item = anyhow::Result<File>> {
+    files_with_extension(path, "java")
+}
+
+fn files_with_extension(
+    path: impl AsRef<Path>,
+    extension: &'static str,
+) -> impl Iterator<Item = anyhow::Result<File>> {
     WalkDir::new(path)
         .into_iter()
-        .filter_map(|entry| -> Option<anyhow::Result<File>> {
+        .filter_map(move |entry| -> Option<anyhow::Result<File>> {
             match entry {
                 Ok(entry) => {
-                    if entry.path().extension().map_or(false, |e| e == "rs") {
+                    if entry.path().extension().map_or(false, |e| e == extension) {
                         Some(Ok(File {
                             path: entry.path().to_path_buf(),
                             contents: match std::fs::read_to_string(entry.path()) {
@@ -5,12 +5,20 @@ use duchess_reflect::{
 use proc_macro2::{Span, TokenStream};
 use syn::spanned::Spanned;
 
-use crate::{files::File, java_compiler::JavaCompiler, log, shim_writer::ShimWriter};
+use crate::{
+    files::File,
+    java_compiler::{JavaCompiler, JavaFile},
+    log,
+    shim_writer::ShimWriter,
+};
 
-pub fn process_impl(compiler: &JavaCompiler, file: &File, offset: usize) -> anyhow::Result<()> {
+pub fn process_impl(
+    compiler: &JavaCompiler,
+    file: &File,
+    offset: usize,
+) -> anyhow::Result<JavaFile> {
     let the_impl: JavaInterfaceImpl = syn::parse_str(file.rust_slice_from(offset))?;
-    the_impl.generate_shim(compiler)?;
-    Ok(())
+    the_impl.generate_shim(compiler)
 }
 
 struct JavaInterfaceImpl {
@@ -31,7 +39,7 @@ impl syn::parse::Parse for JavaInterfaceImpl {
 }
 
 impl JavaInterfaceImpl {
-    fn generate_shim(&self, compiler: &JavaCompiler) -> anyhow::Result<()> {
+    fn generate_shim(&self, compiler: &JavaCompiler) -> anyhow::Result<JavaFile> {
         let mut reflector = JavapReflector::new(compiler.configuration());
         let (java_interface_ref, java_interface_span) = self.java_interface()?;
         let java_interface_info =
@@ -50,7 +58,7 @@ impl JavaInterfaceImpl {
 
         log!("compiled to {}", java_file.rs_path.display());
 
-        Ok(())
+        Ok(java_file)
     }
 
     fn java_interface(&self) -> anyhow::Result<(ClassRef, Span)> {
@@ -1,9 +1,12 @@
 use anyhow::Context;
 use duchess_reflect::config::Configuration;
-use std::{path::PathBuf, process::Command};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 use tempfile::TempDir;
 
-use crate::code_writer::CodeWriter;
+use crate::{code_writer::CodeWriter, files::File};
 
 pub struct JavaCompiler {
     configuration: Configuration,
@@ -17,6 +20,7 @@ pub struct JavaCompiler {
 }
 
 pub struct JavaFile {
+    pub jni_name: String,
     pub java_path: PathBuf,
     pub class_path: PathBuf,
     pub rs_path: PathBuf,
@@ -73,6 +77,7 @@ impl JavaCompiler {
             .with_extension("class");
         let rs_path = self.out_dir.join(format!("{}.rs", class_name));
         JavaFile {
+            jni_name: format!("{}/{}", package.replace('.', "/"), class_name),
             java_path,
             class_path,
             rs_path,
@@ -118,7 +123,7 @@ impl JavaCompiler {
             let mut rs_file = std::fs::File::create(&java_file.rs_path)?;
             let mut cw = CodeWriter::new(&mut rs_file);
 
-            write!(cw, "pub const JAVA_SOURCE: &str = {source_text:?}")?;
+            write!(cw, "pub const JAVA_SOURCE: &str = {source_text:?};")?;
 
             write!(cw, "pub const CLASS_BYTES: &[u8] = &[")?;
             for byte in class_bytes {
@@ -129,6 +134,57 @@ impl JavaCompiler {
 
         Ok(())
     }
+
+    /// Compiles a `.java` file found under `src_root`, in the package given by its directory
+    /// relative to `src_root` (e.g. `com/example/Helper.java` is `com.example.Helper`).
+    pub fn compile_java_source(&self, src_root: &Path, source: &File) -> anyhow::Result<JavaFile> {
+        let relative = source.path.strip_prefix(src_root)?;
+        let class_name = relative
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .with_context(|| format!("invalid file name `{}`", source.path.display()))?;
+        let package = relative
+            .parent()
+            .into_iter()
+            .flat_map(|parent| parent.iter())
+            .map(|part| part.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(".");
+        if package.is_empty() {
+            anyhow::bail!(
+                "`{}` must be in a package directory below `{}`",
+                source.path.display(),
+                src_root.display()
+            );
+        }
+
+        let java_file = self.java_file(&package, class_name);
+        std::fs::write(&java_file.java_path, &source.contents)
+            .with_context(|| format!("writing to `{}`", java_file.java_path.display()))?;
+        self.compile(&java_file)?;
+        Ok(java_file)
+    }
+
+    /// Writes the bytecode of the given compiled files to `duchess_embedded_classes.rs` in the output
+    /// directory, as an expression of type `&[duchess::EmbeddedClass]` for `duchess::embedded_classes!`.
+    pub fn write_embedded_classes(&self, java_files: &[JavaFile]) -> anyhow::Result<()> {
+        let rs_path = self.out_dir.join("duchess_embedded_classes.rs");
+        let mut rs_file = std::fs::File::create(&rs_path)
+            .with_context(|| format!("writing to `{}`", rs_path.display()))?;
+        let mut cw = CodeWriter::new(&mut rs_file);
+
+        write!(cw, "&[")?;
+        for java_file in java_files {
+            let class_bytes = java_file.compiled_bytes()?;
+            write!(cw, "duchess::EmbeddedClass::new(")?;
+            write!(cw, "{:?},", java_file.jni_name)?;
+            write!(cw, "&{class_bytes:?},")?;
+            write!(cw, "),")?;
+        }
+        write!(cw, "]")?;
+
+        Ok(())
+    }
 }
 
 impl JavaFile {
@@ -36,6 +36,7 @@ pub use duchess_reflect::config::Configuration;
 pub struct DuchessBuildRs {
     configuration: Configuration,
     src_path: PathBuf,
+    java_src_path: Option<PathBuf>,
     in_cargo: bool,
     temporary_dir: Option<PathBuf>,
 }
@@ -45,6 +46,7 @@ impl Default for DuchessBuildRs {
         DuchessBuildRs {
             configuration: Configuration::default(),
             src_path: PathBuf::from("."),
+            java_src_path: None,
             in_cargo: std::env::var("CARGO").is_ok() && std::env::var("OUT_DIR").is_ok(),
             temporary_dir: None,
         }
@@ -72,6 +74,14 @@ impl DuchessBuildRs {
         self
     }
 
+    /// Configure a directory of `.java` files to compile and embed into the crate, laid out by
+    /// package (e.g. `com/example/Helper.java`). The compiled classes are included in
+    /// `duchess::embedded_classes!()`, so they need not be on the classpath at runtime.
+    pub fn with_java_src_path(mut self, java_src_path: PathBuf) -> Self {
+        self.java_src_path = Some(java_src_path);
+        self
+    }
+
     /// Where to store temporary files (generated java, class files that are not being exported).
     /// If unset, a fresh temporary directory is created that will be wiped up later.
     pub fn with_temporary_dir(mut self, path: impl AsRef<Path>) -> Self {
@@ -101,6 +111,7 @@ impl DuchessBuildRs {
             files::rs_files(&self.src_path).count()
         );
         let mut reflector = JavapReflector::new(&self.configuration);
+        let mut shims = vec![];
         for rs_file in files::rs_files(&self.src_path) {
             let rs_file = rs_file?;
             let mut watch_file = false;
@@ -111,8 +122,10 @@ impl DuchessBuildRs {
 
             for capture in re::impl_java_interface().captures_iter(&rs_file.contents) {
                 let std::ops::Range { start, end: _ } = capture.get(0).unwrap().range();
-                impl_java_trait::process_impl(compiler, &rs_file, start)
-                    .with_context(|| "failed to parse impl")?;
+                shims.push(
+                    impl_java_trait::process_impl(compiler, &rs_file, start)
+                        .with_context(|| "failed to parse impl")?,
+                );
                 watch_file = true;
             }
 
@@ -120,9 +133,26 @@ impl DuchessBuildRs {
                 println!("cargo:rerun-if-changed={}", rs_file.path.display());
             }
         }
+        if let Some(java_src_path) = &self.java_src_path {
+            for java_file in files::java_files(java_src_path) {
+                let java_file = java_file?;
+                eprintln!("compiling {:?}", java_file.path);
+                shims.push(
+                    compiler
+                        .compile_java_source(java_src_path, &java_file)
+                        .with_context(|| format!("failed to compile {:?}", java_file.path))?,
+                );
+            }
+
+            if self.in_cargo {
+                println!("cargo:rerun-if-changed={}", java_src_path.display());
+            }
+        }
+
         let out_dir = std::env::var("OUT_DIR").unwrap();
         log!("dumping {} classes to {out_dir}", reflector.len());
         reflector.dump_to(Path::new(&out_dir))?;
+        compiler.write_embedded_classes(&shims)?;
         println!("cargo::rustc-env=DUCHESS_OUT_DIR={}", out_dir);
         if let Some(classpath) = self.configuration.classpath() {
             println!("cargo::rustc-env=CLASSPATH={}", classpath);
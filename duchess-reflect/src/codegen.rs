@@ -201,6 +201,7 @@ impl ClassInfo {
         let struct_name = self.struct_name();
 
         let java_class_generics = self.class_generic_names();
+        let jni_class_name = self.jni_class_name();
 
         let jni_descriptor = jni_c_str(constructor.descriptor(&self.generics_scope()), self.span);
 
@@ -211,6 +212,7 @@ impl ClassInfo {
             duchess::semver_unstable::setup_constructor! {
                 struct_name: [#struct_name],
                 java_class_generics: [#(#java_class_generics,)*],
+                jni_class_name: [#jni_class_name],
                 input_names: [#(#input_names,)*],
                 input_ty_tts: [#(#input_ty_tts,)*],
                 input_ty_ops: [#(#input_ty_ops,)*],
@@ -300,6 +302,7 @@ impl ClassInfo {
     fn inherent_object_method(&self, method: &Method) -> syn::Result<TokenStream> {
         let struct_name = self.struct_name();
         let java_class_generics = self.class_generic_names();
+        let jni_class_name = self.jni_class_name();
         let mut sig = Signature::new(&method.name, self.span, &self.generics)
             .with_internal_generics(&method.generics)?;
 
@@ -328,6 +331,7 @@ impl ClassInfo {
             duchess::semver_unstable::setup_inherent_object_method! {
                 struct_name: [#struct_name],
                 java_class_generics: [#(#java_class_generics,)*],
+                jni_class_name: [#jni_class_name],
                 rust_method_name: [#rust_method_name],
                 rust_method_generics: [#(#rust_method_generics,)*],
                 input_names: [#(#input_names,)*],
@@ -351,6 +355,7 @@ impl ClassInfo {
 
         let struct_name = self.struct_name();
         let java_class_generics = self.class_generic_names();
+        let jni_class_name = self.jni_class_name();
 
         let mut sig = Signature::new(&method.name, self.span, &self.generics)
             .with_internal_generics(&method.generics)?;
@@ -375,6 +380,7 @@ impl ClassInfo {
         Ok(quote!(duchess::semver_unstable::setup_static_method! {
             struct_name: [#struct_name],
             java_class_generics: [#(#java_class_generics,)*],
+            jni_class_name: [#jni_class_name],
             rust_method_name: [#rust_method_name],
             rust_method_generics: [#(#rust_method_generics,)*],
             input_names: [#(#input_names,)*],
@@ -397,6 +403,7 @@ impl ClassInfo {
 
         let struct_name = self.struct_name();
         let java_class_generics = self.class_generic_names();
+        let jni_class_name = self.jni_class_name();
 
         let mut sig = Signature::new(&field.name, self.span, &self.generics);
 
@@ -414,6 +421,7 @@ impl ClassInfo {
             duchess::semver_unstable::setup_static_field_getter! {
                 struct_name: [#struct_name],
                 java_class_generics: [#(#java_class_generics,)*],
+                jni_class_name: [#jni_class_name],
                 rust_field_name: [#rust_field_name],
                 field_ty: [#field_ty],
                 sig_where_clauses: [#(#sig_where_clauses,)*],
@@ -0,0 +1,22 @@
+package duchess;
+
+import java.util.function.BiConsumer;
+
+/**
+ * Passes the outcome of a {@code CompletionStage} to Rust, for awaiting Java futures from Rust code.
+ * Registered with {@code whenComplete}, so {@link #accept} is called exactly once.
+ */
+public final class CompletionCallback implements BiConsumer<Object, Throwable> {
+    private final long nativePointer;
+
+    CompletionCallback(long nativePointer) {
+        this.nativePointer = nativePointer;
+    }
+
+    @Override
+    public void accept(Object value, Throwable error) {
+        complete(nativePointer, value, error);
+    }
+
+    private static native void complete(long nativePointer, Object value, Throwable error);
+}
@@ -3,6 +3,7 @@ macro_rules! setup_constructor {
     (
         struct_name: [$S:ident],
         java_class_generics: [$($G:ident,)*],
+        jni_class_name: [$jni_class_name:expr],
         input_names: [$($I:ident,)*],
         input_ty_tts: [$($I_ty:tt,)*],
         input_ty_ops: [$($I_op:path,)*],
@@ -60,6 +61,12 @@ macro_rules! setup_constructor {
                         duchess::semver_unstable::find_constructor(jvm, &class, $jni_descriptor)
                     })?;
 
+                    let _span = duchess::semver_unstable::java_call(
+                        "constructor",
+                        $jni_class_name,
+                        unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"<init>\0") },
+                        $jni_descriptor,
+                    );
                     let env = jvm.env();
                     let obj: ::core::option::Option<duchess::Local<$S<$($G,)*>>> = unsafe {
                         env.invoke(|env| env.NewObjectA, |env, f| f(
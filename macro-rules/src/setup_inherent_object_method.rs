@@ -4,6 +4,7 @@ macro_rules! setup_inherent_object_method {
         struct_name: [$S:ident],
 
         java_class_generics: [$($G:ident,)*],
+        jni_class_name: [$jni_class_name:expr],
 
         // Snake case version of java method name
         rust_method_name: [$M:ident],
@@ -86,6 +87,7 @@ macro_rules! setup_inherent_object_method {
                         duchess::semver_unstable::find_method(jvm, &class, $jni_method, $jni_descriptor, false)
                     })?;
 
+                    let _span = duchess::semver_unstable::java_call("method", $jni_class_name, $jni_method, $jni_descriptor);
                     unsafe {
                         jvm.env().invoke(
                             duchess::semver_unstable::jni_call_fn!($O_ty),
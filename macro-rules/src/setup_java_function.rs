@@ -40,6 +40,9 @@ macro_rules! setup_java_function {
         // The appropriate function from `semver_unstable` to call.
         native_function_returning: $native_function_returning:ident,
 
+        // The JNI name of the class as a string literal (e.g., `"java/lang/Object"`).
+        class_name_literal: $class_name_literal:expr,
+
         // The name of the method as a string literal.
         method_name_literal: $method_name_literal:expr,
 
@@ -62,6 +65,11 @@ macro_rules! setup_java_function {
                 // callable otherwise (presuming user doesn't directly invoke it
                 // thanks to the `#[no_mangle]` attribute, in which case I'd say they are
                 // asking for a problem).
+                let _span = duchess::semver_unstable::native_callback(
+                    $class_name_literal,
+                    $method_name_literal,
+                    $signature_literal,
+                );
                 unsafe {
                     duchess::semver_unstable::$native_function_returning::<
                         $rust_return_ty,
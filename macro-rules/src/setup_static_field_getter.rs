@@ -3,6 +3,7 @@ macro_rules! setup_static_field_getter {
     (
         struct_name: [$S:ident],
         java_class_generics: [$($G:ident,)*],
+        jni_class_name: [$jni_class_name:expr],
         rust_field_name: [$F:ident],
         field_ty: [$F_ty:tt],
         sig_where_clauses: [$($SIG:tt)*],
@@ -46,6 +47,7 @@ macro_rules! setup_static_field_getter {
                     })?;
 
                     let class = <$S<$($G,)*> as duchess::JavaObject>::class(jvm)?;
+                    let _span = duchess::semver_unstable::java_call("field", $jni_class_name, $jni_field, $jni_descriptor);
                     unsafe {
                         jvm.env().invoke(
                             duchess::semver_unstable::jni_static_field_get_fn!($F_ty),
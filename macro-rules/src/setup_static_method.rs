@@ -3,6 +3,7 @@ macro_rules! setup_static_method {
     (
         struct_name: [$S:ident],
         java_class_generics: [$($G:ident,)*],
+        jni_class_name: [$jni_class_name:expr],
         rust_method_name: [$M:ident],
         rust_method_generics: [$($MG:ident,)*],
         input_names: [$($I:tt,)*],
@@ -73,6 +74,7 @@ macro_rules! setup_static_method {
                     })?;
 
                     let class = <$S<$($G,)*> as duchess::JavaObject>::class(jvm)?;
+                    let _span = duchess::semver_unstable::java_call("method", $jni_class_name, $jni_method, $jni_descriptor);
                     unsafe {
                         jvm.env().invoke(
                             duchess::semver_unstable::jni_static_call_fn!($O_ty),
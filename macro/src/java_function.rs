@@ -74,6 +74,7 @@ pub fn java_function(selector: MethodSelector, input: syn::ItemFn) -> syn::Resul
     let abi_argument_tys: Vec<_> = user_arguments.iter().map(|ua| &ua.ty).collect();
 
     // Literals for giving to JNI.
+    let class_name_literal = Literal::string(&class_info.name().to_jni_name());
     let method_name_literal = Literal::string(&selector.method_name());
     let signature_literal = Literal::string(
         &driver
@@ -118,6 +119,7 @@ pub fn java_function(selector: MethodSelector, input: syn::ItemFn) -> syn::Resul
             abi_return_ty: #abi_return_ty,
             rust_return_ty: #rust_return_ty,
             native_function_returning: #native_function_returning,
+            class_name_literal: #class_name_literal,
             method_name_literal: #method_name_literal,
             signature_literal: #signature_literal,
         }
@@ -1,13 +1,15 @@
-use std::marker::PhantomData;
+use std::{cell::Cell, ffi::c_void, marker::PhantomData};
 
 use crate::{
     cast::Upcast,
     java::{self, lang::Class},
-    jvm::JavaView,
+    jvm::{JavaScalar, JavaView, JvmRefOp},
+    ops::{IntoJava, IntoScalar, JavaConstructor},
+    raw::{EnvPtr, ObjectPtr},
     semver_unstable::{FromRef, JavaObjectExt},
     to_java::ToJavaImpl,
-    AsJRef, Error, IntoRust, JDeref, JavaObject, JavaType, Jvm, JvmOp, Local, Nullable,
-    ScalarMethod, TryJDeref,
+    AsJRef, Error, IntoRust, JDeref, JavaObject, JavaType, Jvm, JvmOp, Local, NullJRef, Nullable,
+    ScalarMethod, ToJava, TryJDeref,
 };
 
 pub struct JavaArray<T> {
@@ -120,11 +122,486 @@ where
         let this = self.this.do_jni(jvm)?;
         let this = this.as_jref()?.as_raw();
 
-        let len = unsafe {
-            jvm.env()
-                .invoke_unchecked(|env| env.GetArrayLength, |env, f| f(env, this.as_ptr()))
+        Ok(array_length(jvm.env(), this))
+    }
+}
+
+fn array_length(env: EnvPtr<'_>, array: ObjectPtr) -> jni_sys::jsize {
+    // SAFETY: callers only pass references to Java arrays
+    unsafe { env.invoke_unchecked(|env| env.GetArrayLength, |env, f| f(env, array.as_ptr())) }
+}
+
+/// A scalar type whose Java arrays (e.g. `int[]`) can be read and written in place.
+pub trait JavaPrimitive: JavaScalar + Copy {
+    /// Copies `len` elements starting at `start` from `array` into `buf` with `Get<Type>ArrayRegion`.
+    ///
+    /// # Safety
+    ///
+    /// `array` must be an array of `Self` and `buf` must be valid for `len` writes.
+    unsafe fn get_region<'jvm>(
+        env: EnvPtr<'jvm>,
+        array: ObjectPtr,
+        start: jni_sys::jsize,
+        len: jni_sys::jsize,
+        buf: *mut Self,
+    ) -> crate::LocalResult<'jvm, ()>;
+
+    /// Copies `len` elements from `buf` into `array` starting at `start` with `Set<Type>ArrayRegion`.
+    ///
+    /// # Safety
+    ///
+    /// `array` must be an array of `Self` and `buf` must be valid for `len` reads.
+    unsafe fn set_region<'jvm>(
+        env: EnvPtr<'jvm>,
+        array: ObjectPtr,
+        start: jni_sys::jsize,
+        len: jni_sys::jsize,
+        buf: *const Self,
+    ) -> crate::LocalResult<'jvm, ()>;
+}
+
+/// Operations that access the elements of a primitive Java array (e.g. `int[]`) in place,
+/// rather than copying the entire array into a `Vec` or allocating a new one.
+pub trait JavaPrimitiveArrayExt<T: JavaPrimitive>: JvmOp {
+    /// Copies the elements of the array starting at `offset` into `buf`, filling it completely.
+    /// Throws `ArrayIndexOutOfBoundsException` if the array is too short.
+    fn get_region(self, offset: jni_sys::jsize, buf: &mut [T]) -> GetRegion<'_, Self, T>;
+
+    /// Overwrites the elements starting at `offset` with `values`, writing into the existing
+    /// Java array instead of allocating a new one.
+    /// Throws `ArrayIndexOutOfBoundsException` if the array is too short.
+    fn set_region(self, offset: jni_sys::jsize, values: &[T]) -> SetRegion<'_, Self, T>;
+
+    /// Invokes `op` with a mutable view of the array's elements using `GetPrimitiveArrayCritical`,
+    /// which usually avoids copying, and produces its result.
+    /// Changes made by `op` are written back to the Java array.
+    ///
+    /// While `op` runs, the JVM may be unable to garbage collect or may block other threads,
+    /// so `op` should be short and must not block or wait on other JVM threads.
+    fn with_critical<F, R>(self, op: F) -> WithCritical<Self, F, T>
+    where
+        F: Clone + FnOnce(&mut [T]) -> R;
+}
+
+impl<This, T> JavaPrimitiveArrayExt<T> for This
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<T>>,
+    T: JavaPrimitive,
+{
+    fn get_region(self, offset: jni_sys::jsize, buf: &mut [T]) -> GetRegion<'_, Self, T> {
+        GetRegion {
+            this: self,
+            offset,
+            buf: Cell::from_mut(buf).as_slice_of_cells(),
+        }
+    }
+
+    fn set_region(self, offset: jni_sys::jsize, values: &[T]) -> SetRegion<'_, Self, T> {
+        SetRegion {
+            this: self,
+            offset,
+            values,
+        }
+    }
+
+    fn with_critical<F, R>(self, op: F) -> WithCritical<Self, F, T>
+    where
+        F: Clone + FnOnce(&mut [T]) -> R,
+    {
+        WithCritical {
+            this: self,
+            op,
+            element: PhantomData,
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy)]
+pub struct GetRegion<'a, This: JvmOp, T> {
+    this: This,
+    offset: jni_sys::jsize,
+    // Cells rather than `&mut [T]`, so that the operation can be cloned like any other
+    buf: &'a [Cell<T>],
+}
+
+impl<This, T> JvmOp for GetRegion<'_, This, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<T>>,
+    T: JavaPrimitive,
+{
+    type Output<'jvm> = ();
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let Ok(len) = self.buf.len().try_into() else {
+            return Err(Error::SliceTooLong(self.buf.len()));
         };
-        Ok(len)
+
+        let this = self.this.do_jni(jvm)?;
+        let this = this.as_jref()?.as_raw();
+
+        // `Cell<T>` has the same layout as `T`, and cells may be written through a shared reference
+        let buf = self.buf.as_ptr().cast_mut().cast::<T>();
+        unsafe {
+            // SAFETY: this is an array of T and buf has room for len elements
+            T::get_region(jvm.env(), this, self.offset, len, buf)
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy)]
+pub struct SetRegion<'a, This: JvmOp, T> {
+    this: This,
+    offset: jni_sys::jsize,
+    values: &'a [T],
+}
+
+impl<This, T> JvmOp for SetRegion<'_, This, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<T>>,
+    T: JavaPrimitive,
+{
+    type Output<'jvm> = ();
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let Ok(len) = self.values.len().try_into() else {
+            return Err(Error::SliceTooLong(self.values.len()));
+        };
+
+        let this = self.this.do_jni(jvm)?;
+        let this = this.as_jref()?.as_raw();
+
+        unsafe {
+            // SAFETY: this is an array of T and values holds len elements
+            T::set_region(jvm.env(), this, self.offset, len, self.values.as_ptr())
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, F: Copy)]
+pub struct WithCritical<This: JvmOp, F: Clone, T> {
+    this: This,
+    op: F,
+    element: PhantomData<fn() -> T>,
+}
+
+impl<This, F, R, T> JvmOp for WithCritical<This, F, T>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<T>>,
+    F: Clone + FnOnce(&mut [T]) -> R,
+    T: JavaPrimitive,
+{
+    type Output<'jvm> = R;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let this = self.this.do_jni(jvm)?;
+        let this = this.as_jref()?.as_raw();
+
+        let env = jvm.env();
+        let len = array_length(env, this) as usize;
+        if len == 0 {
+            return Ok((self.op)(&mut []));
+        }
+
+        let elements = unsafe {
+            // SAFETY: this is a primitive array; no other JNI calls are made until it is released
+            env.invoke_unchecked(
+                |env| env.GetPrimitiveArrayCritical,
+                |env, f| f(env, this.as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        if elements.is_null() {
+            env.check_exception()?;
+            return Err(Error::JvmInternal(
+                "GetPrimitiveArrayCritical returned null".to_string(),
+            ));
+        }
+
+        // Release the array even if `op` panics, otherwise the thread is left in a critical region
+        let _release = ReleaseCritical {
+            env,
+            array: this,
+            elements,
+        };
+
+        // SAFETY: the JVM guarantees `elements` points to `len` elements of type T until released
+        let slice = unsafe { std::slice::from_raw_parts_mut(elements.cast::<T>(), len) };
+        Ok((self.op)(slice))
+    }
+}
+
+struct ReleaseCritical<'jvm> {
+    env: EnvPtr<'jvm>,
+    array: ObjectPtr,
+    elements: *mut c_void,
+}
+
+impl Drop for ReleaseCritical<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            // SAFETY: elements was returned by GetPrimitiveArrayCritical for array. Mode 0 copies
+            // back any changes (if the JVM handed us a copy) and frees the elements.
+            self.env.invoke_unchecked(
+                |env| env.ReleasePrimitiveArrayCritical,
+                |env, f| f(env, self.array.as_ptr(), self.elements, 0),
+            );
+        }
+    }
+}
+
+impl<J> JavaArray<J>
+where
+    J: JavaObject,
+{
+    /// Allocates a new `J[]` of the given length with every element set to null,
+    /// like `new J[length]` in Java.
+    pub fn new(length: impl IntoScalar<jni_sys::jsize>) -> impl JavaConstructor<Self> {
+        NewObjectArray {
+            length: length.into_op(),
+            element: PhantomData,
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; Length: Copy)]
+pub struct NewObjectArray<J, Length: JvmOp> {
+    length: Length,
+    element: PhantomData<J>,
+}
+
+impl<J, Length> JvmOp for NewObjectArray<J, Length>
+where
+    J: JavaObject,
+    Length: for<'jvm> JvmOp<Output<'jvm> = jni_sys::jsize>,
+{
+    type Output<'jvm> = Local<'jvm, JavaArray<J>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let length = self.length.do_jni(jvm)?;
+        let class = J::class(jvm)?;
+
+        let array: Option<Local<JavaArray<J>>> = unsafe {
+            // SAFETY: class is the class of J, so the array can hold elements of type J
+            jvm.env().invoke(
+                |env| env.NewObjectArray,
+                |env, f| f(env, length, class.as_raw().as_ptr(), std::ptr::null_mut()),
+            )
+        }?;
+
+        // NewObjectArray should never return null unless an exception occurred (which we've already checked)
+        array.ok_or_else(|| {
+            Error::JvmInternal(format!(
+                "failed to allocate `{}[{}]`",
+                std::any::type_name::<J>(),
+                length
+            ))
+        })
+    }
+}
+
+impl<J, Length> std::ops::Deref for NewObjectArray<J, Length>
+where
+    J: JavaObject,
+    Length: for<'jvm> JvmOp<Output<'jvm> = jni_sys::jsize>,
+{
+    type Target = <JavaArray<J> as JavaView>::OfOp<Self>;
+
+    fn deref(&self) -> &Self::Target {
+        <Self::Target as FromRef<_>>::from_ref(self)
+    }
+}
+
+/// Operations on the elements of a Java array of objects (e.g. `String[]` or `int[][]`).
+pub trait JavaObjectArrayExt<J: JavaObject>: JvmOp {
+    /// Reads the element at `index`, like `array[index]` in Java.
+    /// Throws `ArrayIndexOutOfBoundsException` if `index` is out of bounds.
+    fn get<I>(self, index: I) -> ArrayGet<Self, J, I::JvmOp>
+    where
+        I: IntoScalar<jni_sys::jsize>;
+
+    /// Writes `value` (which may be null) to the element at `index`, like `array[index] = value` in Java.
+    /// Throws `ArrayIndexOutOfBoundsException` if `index` is out of bounds.
+    fn set<I, V>(self, index: I, value: V) -> ArraySet<Self, J, I::JvmOp, V::JvmOp>
+    where
+        I: IntoScalar<jni_sys::jsize>,
+        V: IntoJava<J>;
+}
+
+impl<This, J> JavaObjectArrayExt<J> for This
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<J>>,
+    J: JavaObject,
+{
+    fn get<I>(self, index: I) -> ArrayGet<Self, J, I::JvmOp>
+    where
+        I: IntoScalar<jni_sys::jsize>,
+    {
+        ArrayGet {
+            this: self,
+            index: index.into_op(),
+            element: PhantomData,
+        }
+    }
+
+    fn set<I, V>(self, index: I, value: V) -> ArraySet<Self, J, I::JvmOp, V::JvmOp>
+    where
+        I: IntoScalar<jni_sys::jsize>,
+        V: IntoJava<J>,
+    {
+        ArraySet {
+            this: self,
+            index: index.into_op(),
+            value: value.into_op(),
+            element: PhantomData,
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Index: Copy)]
+pub struct ArrayGet<This: JvmOp, J, Index: JvmOp> {
+    this: This,
+    index: Index,
+    element: PhantomData<J>,
+}
+
+impl<This, J, Index> JvmOp for ArrayGet<This, J, Index>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<J>>,
+    J: JavaObject,
+    Index: for<'jvm> JvmOp<Output<'jvm> = jni_sys::jsize>,
+{
+    type Output<'jvm> = Option<Local<'jvm, J>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let this = self.this.do_jni(jvm)?;
+        let this = this.as_jref()?.as_raw();
+        let index = self.index.do_jni(jvm)?;
+
+        unsafe {
+            // SAFETY: this is an array of J, so its elements are J objects
+            jvm.env().invoke(
+                |env| env.GetObjectArrayElement,
+                |env, f| f(env, this.as_ptr(), index),
+            )
+        }
+    }
+}
+
+impl<This, J, Index> std::ops::Deref for ArrayGet<This, J, Index>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<J>>,
+    J: JavaObject,
+    Index: for<'jvm> JvmOp<Output<'jvm> = jni_sys::jsize>,
+{
+    type Target = <J as JavaView>::OfOp<Self>;
+
+    fn deref(&self) -> &Self::Target {
+        <Self::Target as FromRef<_>>::from_ref(self)
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Index: Copy, Value: Copy)]
+pub struct ArraySet<This: JvmOp, J: JavaObject, Index: JvmOp, Value: JvmRefOp<J>> {
+    this: This,
+    index: Index,
+    value: Value,
+    element: PhantomData<J>,
+}
+
+impl<This, J, Index, Value> JvmOp for ArraySet<This, J, Index, Value>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<JavaArray<J>>,
+    J: JavaObject,
+    Index: for<'jvm> JvmOp<Output<'jvm> = jni_sys::jsize>,
+    Value: JvmRefOp<J>,
+{
+    type Output<'jvm> = ();
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let this = self.this.do_jni(jvm)?;
+        let this = this.as_jref()?.as_raw();
+        let index = self.index.do_jni(jvm)?;
+        let value = self.value.into_as_jref(jvm)?;
+        let value = match value.as_jref() {
+            Ok(v) => v.as_raw().as_ptr(),
+            Err(NullJRef) => std::ptr::null_mut(),
+        };
+
+        unsafe {
+            // SAFETY: this is an array of J and value is a J (or null)
+            jvm.env().invoke(
+                |env| env.SetObjectArrayElement,
+                |env, f| f(env, this.as_ptr(), index, value),
+            )
+        }
+    }
+}
+
+impl<E, JE> ToJavaImpl<java::Array<JE>> for [E]
+where
+    E: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object> + Upcast<JE>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::Array<JE>>>> {
+        let Ok(len) = rust.len().try_into() else {
+            return Err(Error::SliceTooLong(rust.len()));
+        };
+
+        let array = JavaArray::<JE>::new(len).do_jni(jvm)?;
+        for (index, element) in (0..len).zip(rust) {
+            (&array).set(index, element.to_java::<JE>()).do_jni(jvm)?;
+        }
+
+        Ok(Some(array))
+    }
+}
+
+impl<E, JE> ToJavaImpl<java::Array<JE>> for Vec<E>
+where
+    E: ToJavaImpl<JE>,
+    JE: Upcast<java::lang::Object> + Upcast<JE>,
+{
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::Array<JE>>>> {
+        <[E] as ToJavaImpl<java::Array<JE>>>::to_java_impl(rust, jvm)
+    }
+}
+
+/// Converts each element of an object array. Null elements produce [`Error::NullDeref`];
+/// use [`JavaObjectArrayExt::get`] to handle them individually.
+impl<J, R> IntoRust<Vec<R>> for &JavaArray<J>
+where
+    J: JavaObject,
+    for<'a> &'a J: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Vec<R>> {
+        let len = self.length().do_jni(jvm)?;
+        let mut vec = Vec::with_capacity(len as usize);
+
+        for index in 0..len {
+            let element = self.get(index).assert_not_null().do_jni(jvm)?;
+            vec.push(element.into_rust(jvm)?);
+        }
+
+        Ok(vec)
     }
 }
 
@@ -183,7 +660,41 @@ macro_rules! primitive_array {
                     rust: &Self,
                     jvm: &mut Jvm<'jvm>,
                 ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::Array<$rust>>>> {
-                    Ok(Some(rust.do_jni(jvm)?))
+                    Ok(Some(rust.as_slice().do_jni(jvm)?))
+                }
+            }
+
+            impl JavaPrimitive for $rust {
+                unsafe fn get_region<'jvm>(
+                    env: EnvPtr<'jvm>,
+                    array: ObjectPtr,
+                    start: jni_sys::jsize,
+                    len: jni_sys::jsize,
+                    buf: *mut Self,
+                ) -> crate::LocalResult<'jvm, ()> {
+                    env.invoke(|env| env.$get_fn, |env, f| f(
+                        env,
+                        array.as_ptr(),
+                        start,
+                        len,
+                        buf.cast::<jni_sys::$java_ty>(),
+                    ))
+                }
+
+                unsafe fn set_region<'jvm>(
+                    env: EnvPtr<'jvm>,
+                    array: ObjectPtr,
+                    start: jni_sys::jsize,
+                    len: jni_sys::jsize,
+                    buf: *const Self,
+                ) -> crate::LocalResult<'jvm, ()> {
+                    env.invoke(|env| env.$set_fn, |env, f| f(
+                        env,
+                        array.as_ptr(),
+                        start,
+                        len,
+                        buf.cast::<jni_sys::$java_ty>(),
+                    ))
                 }
             }
 
@@ -0,0 +1,115 @@
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{mpsc, Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use once_cell::sync::OnceCell;
+use tokio::sync::oneshot;
+
+use crate::{Error, Jvm};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+static POOL_SIZE: OnceCell<usize> = OnceCell::new();
+
+static POOL: OnceCell<mpsc::Sender<Job>> = OnceCell::new();
+
+/// Sets the number of threads that run [`JvmOp::execute_async`](`crate::JvmOp::execute_async`) operations.
+/// Only the first call has any effect, and only before the pool is started.
+pub(crate) fn set_pool_size(size: Option<usize>) {
+    if let Some(size) = size {
+        let _ = POOL_SIZE.set(size.max(1));
+    }
+}
+
+fn pool_size() -> usize {
+    *POOL_SIZE.get_or_init(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4)
+    })
+}
+
+/// Starts the pool threads, each of them permanently attached to the JVM as a daemon thread
+/// so that they never keep [`Jvm::shutdown`] waiting.
+fn start_pool() -> mpsc::Sender<Job> {
+    let (sender, receiver) = mpsc::channel::<Job>();
+    let receiver = Arc::new(Mutex::new(receiver));
+
+    for index in 0..pool_size() {
+        let receiver = receiver.clone();
+        let spawned = std::thread::Builder::new()
+            .name(format!("duchess-async-{index}"))
+            .spawn(move || {
+                if let Err(err) = Jvm::attach_thread_as_daemon() {
+                    // Each operation reports the error again when it fails to attach
+                    tracing::warn!(?err, "couldn't attach async pool thread to JVM");
+                }
+
+                loop {
+                    let job = receiver
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .recv();
+                    let Ok(job) = job else {
+                        break;
+                    };
+                    if std::panic::catch_unwind(AssertUnwindSafe(job)).is_err() {
+                        tracing::warn!("async JVM operation panicked");
+                    }
+                }
+            });
+        if let Err(err) = spawned {
+            tracing::warn!(?err, "couldn't spawn async pool thread");
+        }
+    }
+
+    sender
+}
+
+/// Runs `job` on the pool, returning its result once it is done.
+pub(crate) fn spawn<R>(job: impl FnOnce() -> crate::Result<R> + Send + 'static) -> AsyncExecution<R>
+where
+    R: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    let job: Job = Box::new(move || {
+        // Skip operations whose future was dropped before they started
+        if !sender.is_closed() {
+            let _ = sender.send(job());
+        }
+    });
+
+    let pool = POOL.get_or_init(start_pool);
+    if pool.send(job).is_err() {
+        tracing::warn!("async pool has no threads left to run operations");
+    }
+
+    AsyncExecution { receiver }
+}
+
+/// The result of [`JvmOp::execute_async`](`crate::JvmOp::execute_async`).
+///
+/// Dropping the future cancels the operation if it has not started running yet.
+/// Once started, the operation runs to completion and its result is discarded.
+#[must_use = "futures do nothing unless polled"]
+pub struct AsyncExecution<R> {
+    receiver: oneshot::Receiver<crate::Result<R>>,
+}
+
+impl<R> Future for AsyncExecution<R> {
+    type Output = crate::Result<R>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx).map(|result| {
+            result.unwrap_or_else(|_| {
+                Err(Error::JvmInternal(
+                    "async JVM operation panicked or was never run".to_string(),
+                ))
+            })
+        })
+    }
+}
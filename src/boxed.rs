@@ -0,0 +1,112 @@
+use std::ffi::CStr;
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    find::{find_class, find_method},
+    java,
+    jvm::JavaObjectExt,
+    raw::{IntoJniValue, MethodPtr},
+    semver_unstable::ToJavaImpl,
+    Error, IntoRust, Java, Jvm, JvmOp, Local,
+};
+
+/// Boxes a `long` with `Long.valueOf(long)`, which is not mirrored because it is an overload of `valueOf(String)`.
+pub(crate) fn long_value_of<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    value: i64,
+) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::lang::Long>>> {
+    const CLASS: &CStr = c"java/lang/Long";
+    const NAME: &CStr = c"valueOf";
+    const DESCRIPTOR: &CStr = c"(J)Ljava/lang/Long;";
+    static VALUE_OF: OnceCell<(Java<java::lang::Class>, MethodPtr)> = OnceCell::new();
+
+    let (class, method) = VALUE_OF.get_or_try_init(|| {
+        let class = find_class(jvm, CLASS)?;
+        let method = find_method(jvm, &class, NAME, DESCRIPTOR, true)?;
+        Ok::<_, Error<_>>((jvm.global(&*class), method))
+    })?;
+    let class = class.as_raw();
+    // SAFETY: `method` is the static `Long.valueOf(long)`
+    unsafe {
+        jvm.env().invoke(
+            |env| env.CallStaticObjectMethodA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_ptr(),
+                    method.as_ptr(),
+                    [value.into_jni_value()].as_ptr(),
+                )
+            },
+        )
+    }
+}
+
+macro_rules! boxed_scalar {
+    ($($rust:ty: $java:ident $unbox:ident,)*) => {
+        $(
+            impl ToJavaImpl<java::lang::$java> for $rust {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::lang::$java>>> {
+                    java::lang::$java::value_of(*rust).do_jni(jvm)
+                }
+            }
+
+            impl IntoRust<$rust> for &java::lang::$java {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, $rust> {
+                    self.$unbox().do_jni(jvm)
+                }
+            }
+        )*
+    };
+}
+
+boxed_scalar! {
+    bool: Boolean boolean_value,
+    u16: Character char_value, // java char
+    i8: Byte byte_value,
+    i16: Short short_value,
+    i32: Integer int_value,
+    f32: Float float_value,
+    f64: Double double_value,
+}
+
+impl ToJavaImpl<java::lang::Long> for i64 {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::lang::Long>>> {
+        long_value_of(jvm, *rust)
+    }
+}
+
+impl IntoRust<i64> for &java::lang::Long {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, i64> {
+        self.long_value().do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::CharOutOfRange`] for a `char` outside the Basic Multilingual Plane,
+/// which takes two Java `char`s.
+impl ToJavaImpl<java::lang::Character> for char {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::lang::Character>>> {
+        let Ok(value) = u16::try_from(u32::from(*rust)) else {
+            return Err(Error::CharOutOfRange(u32::from(*rust)));
+        };
+        java::lang::Character::value_of(value).do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::CharOutOfRange`] for half of a surrogate pair, which is not a valid Rust `char`.
+impl IntoRust<char> for &java::lang::Character {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, char> {
+        let value: u16 = self.char_value().do_jni(jvm)?;
+        char::from_u32(value.into()).ok_or(Error::CharOutOfRange(value.into()))
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{java::nio::ByteBuffer, jvm::JavaObjectExt, AsJRef, Error, Jvm, JvmOp, Local};
+
+/// Runs `op` with a direct `java.nio.ByteBuffer` that views `bytes` without copying them.
+///
+/// `bytes` stay mutably borrowed until this function returns, and the buffer is only handed to `op`
+/// as a local reference, so Rust code cannot touch the bytes while Java may be reading or writing them.
+/// Like [`JvmOp::execute`], this runs immediately; inside `op`, use [`JvmOp::execute_with`] rather than `execute`.
+///
+/// Fails with [`Error::SliceTooLong`] if `bytes` has more than `i64::MAX` elements.
+///
+/// # Safety
+///
+/// The JVM cannot see the borrow of `bytes`: the caller must ensure that neither Java code nor `op`
+/// keeps the buffer (or a buffer derived from it, e.g. via `slice()`) past the end of `op`,
+/// e.g. by storing it in a field or by creating a global reference to it with [`Jvm::global`].
+pub unsafe fn with_direct_byte_buffer<R>(
+    bytes: &mut [u8],
+    op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>, &ByteBuffer) -> crate::LocalResult<'jvm, R>,
+) -> crate::Result<R> {
+    let address = bytes.as_mut_ptr();
+    let capacity = bytes.len();
+
+    Jvm::with(|jvm| {
+        let Ok(capacity) = capacity.try_into() else {
+            return Err(Error::SliceTooLong(capacity));
+        };
+
+        let buffer: Option<Local<ByteBuffer>> = unsafe {
+            // SAFETY: address points to capacity bytes that stay borrowed until we return
+            jvm.env().invoke(
+                |env| env.NewDirectByteBuffer,
+                |env, f| f(env, address.cast(), capacity),
+            )
+        }?;
+        let Some(buffer) = buffer else {
+            return Err(Error::JvmInternal(
+                "the JVM does not support JNI access to direct buffers".to_string(),
+            ));
+        };
+
+        op(jvm, &buffer)
+    })
+}
+
+/// Operations that view the memory behind a direct `java.nio.ByteBuffer` (e.g. one created with
+/// `ByteBuffer.allocateDirect`) without copying it.
+///
+/// The view covers the buffer's entire capacity, ignoring its position and limit.
+/// A `ByteBuffer` is shared with Java, which may read or write its memory from any thread at any time,
+/// so the views are `unsafe`: the caller must rule out the accesses that each method lists.
+pub trait JavaByteBufferExt: JvmOp {
+    /// Executes this operation and invokes `op` with the bytes of the resulting direct buffer.
+    /// Fails with [`Error::UnviewableByteBuffer`] if the buffer is not direct.
+    ///
+    /// Like [`JvmOp::execute`], this runs immediately rather than producing a new `JvmOp`.
+    ///
+    /// # Safety
+    ///
+    /// No code, Java or Rust, may write to the buffer's memory while `op` runs,
+    /// including through another buffer that shares it (e.g. one created with `duplicate()` or `slice()`).
+    unsafe fn with_direct_bytes<R>(self, op: impl FnOnce(&[u8]) -> R) -> crate::Result<R>;
+
+    /// Executes this operation and invokes `op` with the mutable bytes of the resulting direct buffer.
+    /// Fails with [`Error::UnviewableByteBuffer`] if the buffer is not direct or is read-only.
+    ///
+    /// Like [`JvmOp::execute`], this runs immediately rather than producing a new `JvmOp`.
+    ///
+    /// # Safety
+    ///
+    /// No other code, Java or Rust, may read or write the buffer's memory while `op` runs,
+    /// including through another buffer that shares it (e.g. one created with `duplicate()` or `slice()`)
+    /// or through another call to `with_direct_bytes` or `with_direct_bytes_mut`.
+    unsafe fn with_direct_bytes_mut<R>(self, op: impl FnOnce(&mut [u8]) -> R) -> crate::Result<R>;
+}
+
+impl<This> JavaByteBufferExt for This
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: AsJRef<ByteBuffer>,
+{
+    unsafe fn with_direct_bytes<R>(self, op: impl FnOnce(&[u8]) -> R) -> crate::Result<R> {
+        Jvm::with(|jvm| {
+            let buffer = self.do_jni(jvm)?;
+            let buffer = buffer.as_jref()?;
+            let (address, capacity) = direct_buffer_memory(jvm, buffer)?;
+
+            // SAFETY: the memory of a direct buffer stays valid while we hold a reference to it,
+            // and the caller guarantees that nothing writes to it while `op` runs
+            let bytes = unsafe { std::slice::from_raw_parts(address, capacity) };
+            Ok(op(bytes))
+        })
+    }
+
+    unsafe fn with_direct_bytes_mut<R>(self, op: impl FnOnce(&mut [u8]) -> R) -> crate::Result<R> {
+        Jvm::with(|jvm| {
+            let buffer = self.do_jni(jvm)?;
+            let buffer = buffer.as_jref()?;
+            if buffer.is_read_only().do_jni(jvm)? {
+                return Err(Error::UnviewableByteBuffer("read-only"));
+            }
+            let (address, capacity) = direct_buffer_memory(jvm, buffer)?;
+
+            // SAFETY: the memory of a direct buffer stays valid while we hold a reference to it,
+            // and the caller guarantees that nothing else accesses it while `op` runs
+            let bytes = unsafe { std::slice::from_raw_parts_mut(address, capacity) };
+            Ok(op(bytes))
+        })
+    }
+}
+
+fn direct_buffer_memory<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    buffer: &ByteBuffer,
+) -> crate::LocalResult<'jvm, (*mut u8, usize)> {
+    let env = jvm.env();
+    let buffer = buffer.as_raw();
+
+    // SAFETY: buffer is a reference to a ByteBuffer; neither function throws
+    let address = unsafe {
+        env.invoke_unchecked(
+            |env| env.GetDirectBufferAddress,
+            |env, f| f(env, buffer.as_ptr()),
+        )
+    };
+    let capacity = unsafe {
+        env.invoke_unchecked(
+            |env| env.GetDirectBufferCapacity,
+            |env, f| f(env, buffer.as_ptr()),
+        )
+    };
+
+    match usize::try_from(capacity) {
+        Ok(capacity) if !address.is_null() => Ok((address.cast(), capacity)),
+        // A zero-capacity direct buffer may have no backing memory at all
+        Ok(0) => Ok((std::ptr::NonNull::dangling().as_ptr(), 0)),
+        _ => Err(Error::UnviewableByteBuffer("non-direct")),
+    }
+}
@@ -0,0 +1,155 @@
+use std::{cell::Cell, ffi::CStr, marker::PhantomData};
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    java::lang::{Class, ClassLoader, Thread},
+    jvm::{JavaObjectExt, JvmRefOp},
+    raw::ObjectPtr,
+    Java, Jvm, JvmOp, Local,
+};
+
+/// Controls how duchess looks up a Java class when JNI `FindClass` cannot find it.
+///
+/// `FindClass` resolves classes with the class loader of the native method that is currently
+/// executing or, on threads that duchess attached to the JVM itself, the system class loader.
+/// Classes loaded by application or plugin class loaders (as in servlet containers or OSGi)
+/// are therefore invisible to it.
+///
+/// Configure this with [`JvmBuilder::class_resolver`](`crate::jvm::JvmBuilder::class_resolver`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClassResolver {
+    /// Only use `FindClass`, unless a class loader was given with [`JvmOp::with_class_loader`].
+    FindClass,
+
+    /// Fall back to `ClassLoader.loadClass` on the loader given with [`JvmOp::with_class_loader`]
+    /// or, if there is none, on the context class loader of the first thread that called a
+    /// Rust native function.
+    #[default]
+    ContextClassLoader,
+}
+
+static CLASS_RESOLVER: OnceCell<ClassResolver> = OnceCell::new();
+
+/// The context class loader captured from the first thread that called a native function (if it had one).
+static CONTEXT_CLASS_LOADER: OnceCell<Option<Java<ClassLoader>>> = OnceCell::new();
+
+thread_local! {
+    /// The class loader given to the innermost active `with_class_loader` on this thread.
+    static SCOPED_CLASS_LOADER: Cell<Option<ObjectPtr>> = const { Cell::new(None) };
+}
+
+/// Sets the process-wide resolver. Only the first call has any effect, as classes that were
+/// already resolved are cached for the lifetime of the JVM.
+pub(crate) fn set_class_resolver(resolver: ClassResolver) {
+    let _ = CLASS_RESOLVER.set(resolver);
+}
+
+fn class_resolver() -> ClassResolver {
+    CLASS_RESOLVER.get().copied().unwrap_or_default()
+}
+
+/// Remembers the context class loader of the current thread if this is the first native function call.
+pub(crate) fn capture_context_class_loader(jvm: &mut Jvm<'_>) {
+    if class_resolver() != ClassResolver::ContextClassLoader {
+        return;
+    }
+
+    CONTEXT_CLASS_LOADER.get_or_init(|| {
+        // If this fails, the exception has already been cleared and we simply go without a fallback
+        let loader = Thread::current_thread()
+            .get_context_class_loader()
+            .do_jni(jvm);
+        match loader {
+            Ok(Some(loader)) => Some(jvm.global(&loader)),
+            Ok(None) | Err(_) => None,
+        }
+    });
+}
+
+/// Returns the class loader to use for classes that `FindClass` cannot find, if any:
+/// the one given to `with_class_loader` or, failing that, the captured context class loader.
+pub(crate) fn fallback_class_loader<'jvm>(jvm: &mut Jvm<'jvm>) -> Option<Local<'jvm, ClassLoader>> {
+    if let Some(scoped) = SCOPED_CLASS_LOADER.with(|loader| loader.get()) {
+        // SAFETY: the scoped loader is kept alive by the `ScopedClassLoader` guard that installed it
+        return Some(jvm.local(unsafe { ClassLoader::from_raw(scoped) }));
+    }
+
+    match CONTEXT_CLASS_LOADER.get() {
+        Some(Some(context)) if class_resolver() == ClassResolver::ContextClassLoader => {
+            Some(jvm.local(context))
+        }
+        _ => None,
+    }
+}
+
+/// Loads the class named `jni_name` (e.g. `java/lang/String`) with `ClassLoader.loadClass`,
+/// returning `None` if there is no class loader to fall back to.
+pub(crate) fn load_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    jni_name: &CStr,
+) -> crate::LocalResult<'jvm, Option<Local<'jvm, Class>>> {
+    let Some(loader) = fallback_class_loader(jvm) else {
+        return Ok(None);
+    };
+
+    let binary_name = jni_name.to_string_lossy().replace('/', ".");
+    loader.load_class(binary_name.as_str()).do_jni(jvm)
+}
+
+/// Makes `loader` the fallback class loader of the current thread until dropped.
+pub(crate) struct ScopedClassLoader<'a> {
+    previous: Option<ObjectPtr>,
+    phantom: PhantomData<&'a ClassLoader>,
+}
+
+impl<'a> ScopedClassLoader<'a> {
+    pub(crate) fn enter(loader: &'a ClassLoader) -> Self {
+        let previous = SCOPED_CLASS_LOADER.with(|scoped| scoped.replace(Some(loader.as_raw())));
+        Self {
+            previous,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Drop for ScopedClassLoader<'_> {
+    fn drop(&mut self) {
+        SCOPED_CLASS_LOADER.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Loader: Copy)]
+pub struct WithClassLoader<This, Loader>
+where
+    This: JvmOp,
+    Loader: JvmRefOp<ClassLoader>,
+{
+    this: This,
+    loader: Loader,
+}
+
+impl<This, Loader> WithClassLoader<This, Loader>
+where
+    This: JvmOp,
+    Loader: JvmRefOp<ClassLoader>,
+{
+    pub(crate) fn new(this: This, loader: Loader) -> Self {
+        Self { this, loader }
+    }
+}
+
+impl<This, Loader> JvmOp for WithClassLoader<This, Loader>
+where
+    This: JvmOp,
+    Loader: JvmRefOp<ClassLoader>,
+{
+    type Output<'jvm> = This::Output<'jvm>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let loader = self.loader.into_as_jref(jvm)?;
+        jvm.with_class_loader(&loader, |jvm| self.this.do_jni(jvm))
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    cast::Upcast,
+    java::{
+        self,
+        util::{Collection, Map},
+        ArrayExt, ObjectArrayExt,
+    },
+    jvm::JavaObjectExt,
+    IntoRust, JavaObject, Jvm, JvmOp,
+};
+
+/// Converts each element of `collection` into a Rust `R`, fetching them all with one `toArray` call.
+/// Null elements produce [`Error::NullDeref`](`crate::Error::NullDeref`).
+fn collect_elements<'jvm, C, J, R, Out>(
+    jvm: &mut Jvm<'jvm>,
+    collection: &C,
+) -> crate::LocalResult<'jvm, Out>
+where
+    C: Upcast<Collection<J>>,
+    J: JavaObject,
+    for<'a> &'a J: IntoRust<R>,
+    Out: Default + Extend<R>,
+{
+    // SAFETY: `Upcast` guarantees that `collection` is also a `Collection<J>`
+    let collection: &Collection<J> = unsafe { collection.as_raw().as_ref() };
+    let array = collection.to_array().assert_not_null().do_jni(jvm)?;
+    let len = array.length().do_jni(jvm)?;
+
+    let mut out = Out::default();
+    for index in 0..len {
+        // Each element is released before fetching the next, so large collections don't exhaust local refs
+        let element = array.get(index).assert_not_null().do_jni(jvm)?;
+        // SAFETY: like the methods of a generic Java class, we trust the collection to only hold `J`s
+        let element: &J = unsafe { element.as_raw().as_ref() };
+        out.extend(Some(element.into_rust(jvm)?));
+    }
+    Ok(out)
+}
+
+/// Converts each entry of `map`, fetching the keys with one `toArray` call and then each value with `get`.
+/// Null keys or values produce [`Error::NullDeref`](`crate::Error::NullDeref`).
+fn collect_entries<'jvm, M, JK, JV, K, V, Out>(
+    jvm: &mut Jvm<'jvm>,
+    map: &M,
+) -> crate::LocalResult<'jvm, Out>
+where
+    M: Upcast<Map<JK, JV>>,
+    JK: JavaObject,
+    JV: JavaObject,
+    for<'a> &'a JK: IntoRust<K>,
+    for<'a> &'a JV: IntoRust<V>,
+    Out: Default + Extend<(K, V)>,
+{
+    // SAFETY: `Upcast` guarantees that `map` is also a `Map<JK, JV>`
+    let map: &Map<JK, JV> = unsafe { map.as_raw().as_ref() };
+    let keys = map.key_set().assert_not_null().do_jni(jvm)?;
+    let keys = keys.to_array().assert_not_null().do_jni(jvm)?;
+    let len = keys.length().do_jni(jvm)?;
+
+    let mut out = Out::default();
+    for index in 0..len {
+        let key = keys.get(index).assert_not_null().do_jni(jvm)?;
+        let value = map.get(&key).assert_not_null().do_jni(jvm)?;
+        // SAFETY: like the methods of a generic Java class, we trust the map to only hold `JK` keys
+        let key: &JK = unsafe { key.as_raw().as_ref() };
+        out.extend(Some((key.into_rust(jvm)?, value.into_rust(jvm)?)));
+    }
+    Ok(out)
+}
+
+/// Implements [`IntoRust`] from a Java collection class or interface into the Rust collections.
+/// Any other subtype of `java.util.Collection` can be upcast to `Collection` first.
+macro_rules! collection_into_rust {
+    ($($collection:ident,)*) => {
+        $(
+            impl<J, R> IntoRust<Vec<R>> for &java::util::$collection<J>
+            where
+                J: JavaObject,
+                for<'a> &'a J: IntoRust<R>,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Vec<R>> {
+                    collect_elements(jvm, self)
+                }
+            }
+
+            impl<J, R> IntoRust<VecDeque<R>> for &java::util::$collection<J>
+            where
+                J: JavaObject,
+                for<'a> &'a J: IntoRust<R>,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, VecDeque<R>> {
+                    collect_elements(jvm, self)
+                }
+            }
+
+            impl<J, R, S> IntoRust<HashSet<R, S>> for &java::util::$collection<J>
+            where
+                J: JavaObject,
+                for<'a> &'a J: IntoRust<R>,
+                R: Eq + Hash,
+                S: BuildHasher + Default,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, HashSet<R, S>> {
+                    collect_elements(jvm, self)
+                }
+            }
+
+            impl<J, R> IntoRust<BTreeSet<R>> for &java::util::$collection<J>
+            where
+                J: JavaObject,
+                for<'a> &'a J: IntoRust<R>,
+                R: Ord,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, BTreeSet<R>> {
+                    collect_elements(jvm, self)
+                }
+            }
+        )*
+    };
+}
+
+collection_into_rust! {
+    Collection,
+    List,
+    ArrayList,
+    Set,
+    HashSet,
+    LinkedHashSet,
+}
+
+/// Implements [`IntoRust`] from a Java map class or interface into the Rust maps.
+///
+/// Null values fail with [`Error::NullDeref`](`crate::Error::NullDeref`): a `HashMap<K, Option<V>>` target
+/// can't be offered next to `HashMap<K, V>`, as the two impls would overlap. Maps that may hold null
+/// values can be read with [`duchess::serde::from_java`](`crate::serde::from_java`) instead, which turns
+/// them into `None`.
+macro_rules! map_into_rust {
+    ($($map:ident,)*) => {
+        $(
+            impl<JK, JV, K, V, S> IntoRust<HashMap<K, V, S>> for &java::util::$map<JK, JV>
+            where
+                JK: JavaObject,
+                JV: JavaObject,
+                for<'a> &'a JK: IntoRust<K>,
+                for<'a> &'a JV: IntoRust<V>,
+                K: Eq + Hash,
+                S: BuildHasher + Default,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, HashMap<K, V, S>> {
+                    collect_entries(jvm, self)
+                }
+            }
+
+            impl<JK, JV, K, V> IntoRust<BTreeMap<K, V>> for &java::util::$map<JK, JV>
+            where
+                JK: JavaObject,
+                JV: JavaObject,
+                for<'a> &'a JK: IntoRust<K>,
+                for<'a> &'a JV: IntoRust<V>,
+                K: Ord,
+            {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, BTreeMap<K, V>> {
+                    collect_entries(jvm, self)
+                }
+            }
+        )*
+    };
+}
+
+map_into_rust! {
+    Map,
+    HashMap,
+    LinkedHashMap,
+}
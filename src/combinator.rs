@@ -0,0 +1,220 @@
+use std::marker::PhantomData;
+
+use crate::{IntoRust, Jvm, JvmOp};
+
+/// Applies a function to the output of an operation, see [`JvmOp::map`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, F: Copy)]
+pub struct Map<This, F, R>
+where
+    This: JvmOp,
+    F: Clone,
+{
+    this: This,
+    f: F,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<This, F, R> Map<This, F, R>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> R,
+{
+    pub(crate) fn new(this: This, f: F) -> Self {
+        Self {
+            this,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, F, R> JvmOp for Map<This, F, R>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> R,
+{
+    type Output<'jvm> = R;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let output = self.this.do_jni(jvm)?;
+        Ok((self.f)(output, jvm))
+    }
+}
+
+/// Applies a fallible function to the output of an operation, see [`JvmOp::and_then`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, F: Copy)]
+pub struct AndThen<This, F, R>
+where
+    This: JvmOp,
+    F: Clone,
+{
+    this: This,
+    f: F,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<This, F, R> AndThen<This, F, R>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+{
+    pub(crate) fn new(this: This, f: F) -> Self {
+        Self {
+            this,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<This, F, R> JvmOp for AndThen<This, F, R>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(This::Output<'jvm>, &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+{
+    type Output<'jvm> = R;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let output = self.this.do_jni(jvm)?;
+        (self.f)(output, jvm)
+    }
+}
+
+/// Calls a function with a reference to the output of an operation, see [`JvmOp::inspect`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, F: Copy)]
+pub struct Inspect<This, F>
+where
+    This: JvmOp,
+    F: Clone,
+{
+    this: This,
+    f: F,
+}
+
+impl<This, F> Inspect<This, F>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(&This::Output<'jvm>, &mut Jvm<'jvm>),
+{
+    pub(crate) fn new(this: This, f: F) -> Self {
+        Self { this, f }
+    }
+}
+
+impl<This, F> JvmOp for Inspect<This, F>
+where
+    This: JvmOp,
+    F: Clone + for<'jvm> FnOnce(&This::Output<'jvm>, &mut Jvm<'jvm>),
+{
+    type Output<'jvm> = This::Output<'jvm>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let output = self.this.do_jni(jvm)?;
+        (self.f)(&output, jvm);
+        Ok(output)
+    }
+}
+
+/// Runs two operations one after the other, see [`join`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Other: Copy)]
+pub struct Join<This, Other>
+where
+    This: JvmOp,
+    Other: JvmOp,
+{
+    this: This,
+    other: Other,
+}
+
+/// Combines two operations into one that runs `a` and then `b`, producing both outputs as a tuple.
+///
+/// To combine more operations, or a number of them only known at runtime, use a tuple or a `Vec`
+/// of operations, which are operations themselves: `(a, b, c).execute()` runs `a`, `b` and `c` in order.
+/// Either way, the first exception stops the remaining operations from running.
+pub fn join<A, B>(a: A, b: B) -> Join<A, B>
+where
+    A: JvmOp,
+    B: JvmOp,
+{
+    Join { this: a, other: b }
+}
+
+impl<This, Other> JvmOp for Join<This, Other>
+where
+    This: JvmOp,
+    Other: JvmOp,
+{
+    type Output<'jvm> = (This::Output<'jvm>, Other::Output<'jvm>);
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let this = self.this.do_jni(jvm)?;
+        let other = self.other.do_jni(jvm)?;
+        Ok((this, other))
+    }
+}
+
+/// A tuple of operations runs each of them in order, producing a tuple of their outputs.
+/// It stops at the first operation that fails.
+macro_rules! tuple_ops {
+    ($(($($op:ident $rust:ident),*),)*) => {
+        $(
+            impl<$($op,)*> JvmOp for ($($op,)*)
+            where
+                $($op: JvmOp,)*
+            {
+                type Output<'jvm> = ($($op::Output<'jvm>,)*);
+
+                #[allow(non_snake_case)]
+                fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+                    let ($($op,)*) = self;
+                    Ok(($($op.do_jni(jvm)?,)*))
+                }
+            }
+
+            impl<$($op, $rust,)*> IntoRust<($($rust,)*)> for ($($op,)*)
+            where
+                $($op: IntoRust<$rust>,)*
+            {
+                #[allow(non_snake_case)]
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, ($($rust,)*)> {
+                    let ($($op,)*) = self;
+                    Ok(($($op.into_rust(jvm)?,)*))
+                }
+            }
+        )*
+    };
+}
+
+tuple_ops! {
+    (A RA, B RB),
+    (A RA, B RB, C RC),
+    (A RA, B RB, C RC, D RD),
+    (A RA, B RB, C RC, D RD, E RE),
+    (A RA, B RB, C RC, D RD, E RE, F RF),
+}
+
+/// A `Vec` of operations runs each of them in order, producing a `Vec` of their outputs.
+/// It stops at the first operation that fails.
+impl<Op> JvmOp for Vec<Op>
+where
+    Op: JvmOp,
+{
+    type Output<'jvm> = Vec<Op::Output<'jvm>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        self.into_iter().map(|op| op.do_jni(jvm)).collect()
+    }
+}
+
+impl<J, R> IntoRust<Vec<R>> for Vec<J>
+where
+    J: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Vec<R>> {
+        self.into_iter().map(|j| j.into_rust(jvm)).collect()
+    }
+}
@@ -0,0 +1,311 @@
+use std::{
+    ffi::c_void,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    cast::Upcast,
+    error::rust_error_to_throwable,
+    find::{find_class, find_constructor},
+    java::{
+        lang::{Class, Object, Throwable},
+        util::{
+            concurrent::{CompletableFuture, CompletionException, CompletionStage},
+            function::BiConsumer,
+        },
+    },
+    jvm::JavaObjectExt,
+    raw::{EnvPtr, IntoJniValue},
+    semver_unstable::ToJavaImpl,
+    AsJRef, Error, Java, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Called with the outcome of a completion stage by the `duchess.CompletionCallback` Java class.
+type Callback =
+    Box<dyn for<'jvm> FnOnce(&mut Jvm<'jvm>, Option<&Object>, Option<&Throwable>) + Send>;
+
+/// A Rust [`Future`] that resolves when a Java `CompletionStage` (such as a `CompletableFuture`) completes.
+///
+/// The value the stage completes with is returned as `Ok`, and `None` if it is `null`. If the stage
+/// completes exceptionally, the exception is returned as [`Error::Thrown`], unwrapped from the
+/// `CompletionException` that Java wraps it in when it propagates through dependent stages.
+///
+/// `Java<CompletableFuture<T>>` implements [`IntoFuture`], so it can be awaited directly.
+///
+/// Dropping the future does not cancel the Java stage, which other Java code may be waiting for.
+/// The callback registered on the stage is only freed once the stage completes, so a stage that
+/// never completes keeps it (and the state it shares with this future) alive for the rest of the program;
+/// cancel such a stage (e.g. with `CompletableFuture.cancel`) to release it.
+#[must_use = "futures do nothing unless polled"]
+pub struct JavaFuture<T: JavaObject> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+struct Shared<T: JavaObject> {
+    result: Option<crate::Result<Option<Java<T>>>>,
+    waker: Option<Waker>,
+}
+
+impl<T: JavaObject> JavaFuture<T> {
+    /// Registers a callback on `stage` with `whenComplete`, so the returned future resolves when it completes.
+    pub fn new(stage: &impl AsJRef<CompletionStage<T>>) -> Self {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+
+        let registered = Jvm::with(|jvm| {
+            let stage = stage.as_jref()?;
+            let callback = new_callback(jvm, {
+                let shared = shared.clone();
+                Box::new(move |jvm, value, error| {
+                    let result = match error {
+                        Some(error) => Err(Error::Thrown(unwrap_completion_exception(jvm, error))),
+                        // SAFETY: `whenComplete` passes the value the stage completed with, which is a `T`
+                        None => {
+                            Ok(value
+                                .map(|value| jvm.global(unsafe { T::from_raw(value.as_raw()) })))
+                        }
+                    };
+                    complete(&shared, result);
+                })
+            })?;
+            stage.when_complete(&callback).do_jni(jvm)?;
+            Ok(())
+        });
+
+        if let Err(error) = registered {
+            complete(&shared, Err(error));
+        }
+
+        JavaFuture { shared }
+    }
+}
+
+fn complete<T: JavaObject>(shared: &Mutex<Shared<T>>, result: crate::Result<Option<Java<T>>>) {
+    let mut shared = shared.lock().unwrap_or_else(PoisonError::into_inner);
+    shared.result = Some(result);
+    if let Some(waker) = shared.waker.take() {
+        waker.wake();
+    }
+}
+
+/// Returns the cause of a `CompletionException`, or `error` itself if it is any other exception.
+fn unwrap_completion_exception(jvm: &mut Jvm<'_>, error: &Throwable) -> Java<Throwable> {
+    let mut cause = || -> crate::LocalResult<'_, Option<Local<'_, Throwable>>> {
+        match error.try_downcast::<CompletionException>().do_jni(jvm)? {
+            Ok(completion) => completion.get_cause().do_jni(jvm),
+            Err(_) => Ok(None),
+        }
+    };
+    match cause() {
+        Ok(Some(cause)) => jvm.global(&cause),
+        Ok(None) | Err(_) => jvm.global(error),
+    }
+}
+
+impl<T: JavaObject> Future for JavaFuture<T> {
+    type Output = crate::Result<Option<Java<T>>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T: JavaObject> IntoFuture for Java<CompletableFuture<T>> {
+    type Output = crate::Result<Option<Java<T>>>;
+    type IntoFuture = JavaFuture<T>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        JavaFuture::new(&self)
+    }
+}
+
+impl<T: JavaObject> IntoFuture for &Java<CompletableFuture<T>> {
+    type Output = crate::Result<Option<Java<T>>>;
+    type IntoFuture = JavaFuture<T>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        JavaFuture::new(self)
+    }
+}
+
+static CALLBACK_CLASS: OnceCell<Java<Class>> = OnceCell::new();
+
+/// Creates a `duchess.CompletionCallback` that runs `callback` when it is called.
+fn new_callback<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    callback: Callback,
+) -> crate::LocalResult<'jvm, Local<'jvm, BiConsumer<Object, Throwable>>> {
+    let class = CALLBACK_CLASS.get_or_try_init::<_, Error<Local<Throwable>>>(|| {
+        let class = find_class(jvm, c"duchess/CompletionCallback")?;
+        let native_method = jni_sys::JNINativeMethod {
+            name: c"complete".as_ptr().cast_mut(),
+            signature: c"(JLjava/lang/Object;Ljava/lang/Throwable;)V"
+                .as_ptr()
+                .cast_mut(),
+            fnPtr: complete_callback as *mut c_void,
+        };
+        // SAFETY: `complete_callback` has the signature of the native method
+        unsafe {
+            jvm.env()
+                .register_native_methods(class.as_raw(), &[native_method])?;
+        }
+        Ok(jvm.global(&class))
+    })?;
+    let constructor = find_constructor(jvm, class, c"(J)V")?;
+
+    let pointer = Box::into_raw(Box::new(callback));
+    let env = jvm.env();
+    // SAFETY: the constructor takes the `long` we pass
+    let callback: crate::LocalResult<'jvm, Option<Local<'jvm, BiConsumer<Object, Throwable>>>> = unsafe {
+        env.invoke(
+            |env| env.NewObjectA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_raw().as_ptr(),
+                    constructor.as_ptr(),
+                    [(pointer as i64).into_jni_value()].as_ptr(),
+                )
+            },
+        )
+    };
+
+    match callback {
+        Ok(Some(callback)) => Ok(callback),
+        result => {
+            // SAFETY: the Java object was not created, so nothing else owns the pointer
+            drop(unsafe { Box::from_raw(pointer) });
+            result?;
+            Err(Error::JvmInternal(
+                "failed to create `duchess.CompletionCallback`".to_string(),
+            ))
+        }
+    }
+}
+
+/// Implements `CompletionCallback.complete(long, Object, Throwable)`.
+unsafe extern "system" fn complete_callback(
+    env: EnvPtr<'_>,
+    _class: jni_sys::jclass,
+    pointer: jni_sys::jlong,
+    value: Option<&Object>,
+    error: Option<&Throwable>,
+) {
+    crate::jvm::native_function_returning_unit::<(), ()>(env, || {
+        // SAFETY: `whenComplete` calls each callback once, so we take back the pointer we created in `new_callback`
+        let callback = unsafe { Box::from_raw(pointer as *mut Callback) };
+        let result = Jvm::with(|jvm| {
+            callback(jvm, value, error);
+            Ok(())
+        });
+        if let Err(err) = result {
+            tracing::warn!(?err, "couldn't complete Rust future from Java");
+        }
+    })
+}
+
+/// Completes a Java `CompletableFuture` with the output of a Rust future, see [`to_completable_future`].
+///
+/// Dropping it before the Rust future finishes cancels the `CompletableFuture`, so Java code
+/// waiting for it gets a `CancellationException` rather than waiting forever.
+#[must_use = "futures do nothing unless polled"]
+pub struct CompleteFuture<T: JavaObject, F: Future> {
+    future: Pin<Box<F>>,
+    java: Option<Java<CompletableFuture<T>>>,
+}
+
+type JavaAndRustFutures<T, F> = (Java<CompletableFuture<T>>, CompleteFuture<T, F>);
+
+/// Creates a Java `CompletableFuture` that is completed with the output of the Rust `future`,
+/// so that Java code can wait for a Rust computation.
+///
+/// The future's output is converted with [`ToJava`](`crate::ToJava`). If it is an `Err`, the
+/// `CompletableFuture` completes exceptionally: a Java exception that travelled through Rust as
+/// [`Error::Thrown`] is passed on as is, and other errors become a `java.lang.RuntimeException`.
+///
+/// Nothing happens until the returned [`CompleteFuture`] is polled, so spawn it on your executor:
+///
+/// ```ignore
+/// let (java_future, complete) = duchess::to_completable_future::<java::lang::String, _>(async {
+///     Ok::<_, Box<dyn std::error::Error + Send + Sync>>(fetch_greeting().await?)
+/// })?;
+/// tokio::spawn(complete);
+/// ```
+pub fn to_completable_future<T, F>(future: F) -> crate::Result<JavaAndRustFutures<T, F>>
+where
+    T: Upcast<Object> + Upcast<T>,
+    F: Future,
+    F::Output: ToJavaImpl<T>,
+{
+    let java: Java<CompletableFuture<T>> = CompletableFuture::new().execute()?;
+    let complete = CompleteFuture {
+        future: Box::pin(future),
+        java: Some(java.execute()?),
+    };
+    Ok((java, complete))
+}
+
+// The Rust future is pinned on the heap, and the Java reference can be moved freely.
+impl<T: JavaObject, F: Future> Unpin for CompleteFuture<T, F> {}
+
+impl<T, F> Future for CompleteFuture<T, F>
+where
+    T: Upcast<Object> + Upcast<T>,
+    F: Future,
+    F::Output: ToJavaImpl<T>,
+{
+    type Output = crate::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let output = match self.future.as_mut().poll(cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => return Poll::Pending,
+        };
+        let java = self
+            .java
+            .take()
+            .expect("`CompleteFuture` polled after completion");
+
+        Poll::Ready(Jvm::with(|jvm| {
+            match F::Output::to_java_impl(&output, jvm) {
+                Ok(value) => {
+                    java.complete(&value).do_jni(jvm)?;
+                }
+                Err(Error::Thrown(exception)) => {
+                    java.complete_exceptionally(&exception).do_jni(jvm)?;
+                }
+                Err(error) => {
+                    let error = error.into_global(jvm);
+                    let exception = rust_error_to_throwable(jvm, &error)?;
+                    java.complete_exceptionally(&exception).do_jni(jvm)?;
+                }
+            }
+            Ok(())
+        }))
+    }
+}
+
+impl<T: JavaObject, F: Future> Drop for CompleteFuture<T, F> {
+    fn drop(&mut self) {
+        let Some(java) = self.java.take() else {
+            return;
+        };
+        if let Err(err) = java.cancel(false).execute() {
+            tracing::warn!(?err, "couldn't cancel Java future for dropped Rust future");
+        }
+    }
+}
@@ -0,0 +1,1111 @@
+//! Calling Java classes that are only known at runtime, through reflection.
+
+use std::{
+    collections::HashMap,
+    ffi::CString,
+    fmt,
+    marker::PhantomData,
+    ptr,
+    sync::{Arc, Mutex, Weak},
+};
+
+use jni_sys::jvalue;
+use once_cell::sync::{Lazy, OnceCell};
+
+use crate::{
+    find::find_class,
+    into_rust::identity_rust_op,
+    java::{
+        self,
+        lang::{
+            reflect::{Constructor, Field, Method},
+            Class, Object,
+        },
+        ArrayExt, ObjectArrayExt,
+    },
+    jvm::JavaObjectExt,
+    raw::{FieldPtr, IntoJniValue, MethodPtr, ObjectPtr},
+    Error, IntoRust, Java, JavaObject, Jvm, JvmOp, Local, LocalResult,
+};
+
+/// `java.lang.reflect.Modifier.STATIC`
+const STATIC: i32 = 0x0008;
+
+/// A Java value passed to or returned from a [`DynClass`] or [`DynObject`] call.
+///
+/// Java strings are converted to and from [`DynValue::String`], all other objects are [`DynValue::Object`].
+/// Methods returning `void` produce [`DynValue::Null`].
+#[derive(Clone, Debug)]
+pub enum DynValue {
+    Null,
+    Boolean(bool),
+    Byte(i8),
+    Char(u16),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Object(DynObject),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Primitive {
+    Boolean,
+    Byte,
+    Char,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+}
+
+impl Primitive {
+    /// Whether a `self` value can be passed where a `to` is expected, by identity or
+    /// [widening](https://docs.oracle.com/javase/specs/jls/se17/html/jls-5.html#jls-5.1.2).
+    fn widens_to(self, to: Primitive) -> bool {
+        use Primitive::*;
+        match self {
+            Boolean | Char if self == to => true,
+            Boolean => false,
+            Byte => matches!(to, Byte | Short | Int | Long | Float | Double),
+            Short => matches!(to, Short | Int | Long | Float | Double),
+            Char => matches!(to, Int | Long | Float | Double),
+            Int => matches!(to, Int | Long | Float | Double),
+            Long => matches!(to, Long | Float | Double),
+            Float => matches!(to, Float | Double),
+            Double => to == Double,
+        }
+    }
+}
+
+/// The type of a parameter, return value or field.
+enum Type {
+    Void,
+    Primitive(Primitive),
+    Reference(Java<Class>),
+}
+
+impl Type {
+    fn of<'jvm>(jvm: &mut Jvm<'jvm>, class: &Class) -> LocalResult<'jvm, Type> {
+        let name: String = class.get_name().assert_not_null().execute_with(jvm)?;
+        Ok(match name.as_str() {
+            "void" => Type::Void,
+            "boolean" => Type::Primitive(Primitive::Boolean),
+            "byte" => Type::Primitive(Primitive::Byte),
+            "char" => Type::Primitive(Primitive::Char),
+            "short" => Type::Primitive(Primitive::Short),
+            "int" => Type::Primitive(Primitive::Int),
+            "long" => Type::Primitive(Primitive::Long),
+            "float" => Type::Primitive(Primitive::Float),
+            "double" => Type::Primitive(Primitive::Double),
+            _ => Type::Reference(jvm.global(class)),
+        })
+    }
+
+    /// Whether `self` can be used where `other` is expected, used to find the most specific overload.
+    fn is_subtype_of(&self, other: &Type, jvm: &mut Jvm<'_>) -> bool {
+        match (self, other) {
+            (Type::Primitive(a), Type::Primitive(b)) => a.widens_to(*b),
+            (Type::Reference(a), Type::Reference(b)) => is_assignable_from(jvm, a, b),
+            _ => false,
+        }
+    }
+
+    /// Whether `arg` can be passed where `self` is expected. Boxing is not supported.
+    fn accepts(&self, arg: &Arg<'_, '_>, jvm: &mut Jvm<'_>) -> bool {
+        match (self, arg) {
+            (Type::Primitive(to), Arg::Primitive(from, _)) => from.widens_to(*to),
+            (Type::Reference(_), Arg::Null) => true,
+            (Type::Reference(class), Arg::Object(object)) => is_instance_of(jvm, object, class),
+            _ => false,
+        }
+    }
+}
+
+fn is_instance_of(jvm: &mut Jvm<'_>, object: &Object, class: &Class) -> bool {
+    let (object, class) = (object.as_raw(), class.as_raw());
+    // SAFETY: both are valid references and `IsInstanceOf` does not throw
+    unsafe {
+        jvm.env().invoke_unchecked(
+            |env| env.IsInstanceOf,
+            |env, f| f(env, object.as_ptr(), class.as_ptr()),
+        ) == jni_sys::JNI_TRUE
+    }
+}
+
+/// Whether a `from` can be cast to a `to`, like `to.isAssignableFrom(from)` in Java.
+fn is_assignable_from(jvm: &mut Jvm<'_>, from: &Class, to: &Class) -> bool {
+    let (from, to) = (from.as_raw(), to.as_raw());
+    // SAFETY: both are valid references and `IsAssignableFrom` does not throw
+    unsafe {
+        jvm.env().invoke_unchecked(
+            |env| env.IsAssignableFrom,
+            |env, f| f(env, from.as_ptr(), to.as_ptr()),
+        ) == jni_sys::JNI_TRUE
+    }
+}
+
+fn is_same_object(jvm: &mut Jvm<'_>, a: &Class, b: &Class) -> bool {
+    let (a, b) = (a.as_raw(), b.as_raw());
+    // SAFETY: both are valid references and `IsSameObject` does not throw
+    unsafe {
+        jvm.env().invoke_unchecked(
+            |env| env.IsSameObject,
+            |env, f| f(env, a.as_ptr(), b.as_ptr()),
+        ) == jni_sys::JNI_TRUE
+    }
+}
+
+/// An argument to a call, converted once before overload resolution.
+enum Arg<'a, 'jvm> {
+    Primitive(Primitive, &'a DynValue),
+    Null,
+    Object(Local<'jvm, Object>),
+}
+
+impl<'a, 'jvm> Arg<'a, 'jvm> {
+    fn new(jvm: &mut Jvm<'jvm>, value: &'a DynValue) -> LocalResult<'jvm, Self> {
+        Ok(match value {
+            DynValue::Null => Arg::Null,
+            DynValue::String(s) => Arg::Object(s.as_str().do_jni(jvm)?.upcast()),
+            DynValue::Object(o) => Arg::Object(jvm.local(o.as_java().as_ref())),
+            _ => Arg::Primitive(value.primitive().unwrap(), value),
+        })
+    }
+
+    /// The argument to pass for a parameter of type `to`, which must accept it.
+    fn to_jni_value(&self, to: &Type) -> jvalue {
+        match (self, to) {
+            (Arg::Primitive(_, value), Type::Primitive(to)) => value.to_jni_value(*to),
+            (Arg::Object(object), _) => (&**object).into_jni_value(),
+            _ => jvalue { l: ptr::null_mut() },
+        }
+    }
+}
+
+/// A public method or constructor.
+struct Executable {
+    method: MethodPtr,
+    params: Vec<Type>,
+    ret: Type,
+}
+
+impl Executable {
+    fn from_method<'jvm>(jvm: &mut Jvm<'jvm>, method: &Method) -> LocalResult<'jvm, Self> {
+        let params = method.get_parameter_types().do_jni(jvm)?;
+        let params = parameter_types(jvm, params)?;
+        let ret = method.get_return_type().assert_not_null().do_jni(jvm)?;
+        Ok(Executable {
+            method: from_reflected_method(jvm, method.as_raw())?,
+            params,
+            ret: Type::of(jvm, &ret)?,
+        })
+    }
+
+    fn from_constructor<'jvm>(
+        jvm: &mut Jvm<'jvm>,
+        constructor: &Constructor,
+    ) -> LocalResult<'jvm, Self> {
+        let params = constructor.get_parameter_types().do_jni(jvm)?;
+        let params = parameter_types(jvm, params)?;
+        Ok(Executable {
+            method: from_reflected_method(jvm, constructor.as_raw())?,
+            params,
+            ret: Type::Void,
+        })
+    }
+
+    fn accepts(&self, args: &[Arg<'_, '_>], jvm: &mut Jvm<'_>) -> bool {
+        self.params.len() == args.len()
+            && self
+                .params
+                .iter()
+                .zip(args)
+                .all(|(param, arg)| param.accepts(arg, jvm))
+    }
+
+    /// Whether every parameter of `self` can be passed to the matching parameter of `other`.
+    fn is_more_specific_than(&self, other: &Executable, jvm: &mut Jvm<'_>) -> bool {
+        self.params
+            .iter()
+            .zip(&other.params)
+            .all(|(a, b)| a.is_subtype_of(b, jvm))
+    }
+}
+
+fn parameter_types<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    classes: Option<Local<'jvm, java::Array<Class>>>,
+) -> LocalResult<'jvm, Vec<Type>> {
+    filter_map_elements(jvm, classes, |jvm, class| Type::of(jvm, class).map(Some))
+}
+
+/// Maps the elements of an array returned by reflection, which are never null, keeping the `Some` results.
+/// Each element's local reference is released before the next one is read, as classes can have many members.
+fn filter_map_elements<'jvm, T: JavaObject, U>(
+    jvm: &mut Jvm<'jvm>,
+    array: Option<Local<'jvm, java::Array<T>>>,
+    mut f: impl FnMut(&mut Jvm<'jvm>, &T) -> LocalResult<'jvm, Option<U>>,
+) -> LocalResult<'jvm, Vec<U>> {
+    let Some(array) = array else {
+        return Ok(vec![]);
+    };
+    let array: &java::Array<T> = &array;
+    let len = array.length().do_jni(jvm)?;
+    let mut mapped = vec![];
+    for index in 0..len {
+        let element = array.get(index).assert_not_null().do_jni(jvm)?;
+        mapped.extend(f(jvm, &element)?);
+    }
+    Ok(mapped)
+}
+
+fn from_reflected_method<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    method: ObjectPtr,
+) -> LocalResult<'jvm, MethodPtr> {
+    // SAFETY: `method` is a live `Method` or `Constructor`
+    let method = unsafe {
+        jvm.env().invoke_unchecked(
+            |env| env.FromReflectedMethod,
+            |env, f| f(env, method.as_ptr()),
+        )
+    };
+    match MethodPtr::new(method) {
+        Some(method) => Ok(method),
+        None => {
+            jvm.env().check_exception()?;
+            Err(Error::JvmInternal(
+                "failed to get the ID of a reflected method".into(),
+            ))
+        }
+    }
+}
+
+fn from_reflected_field<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    field: ObjectPtr,
+) -> LocalResult<'jvm, FieldPtr> {
+    // SAFETY: `field` is a live `Field`
+    let field = unsafe {
+        jvm.env().invoke_unchecked(
+            |env| env.FromReflectedField,
+            |env, f| f(env, field.as_ptr()),
+        )
+    };
+    match FieldPtr::new(field) {
+        Some(field) => Ok(field),
+        None => {
+            jvm.env().check_exception()?;
+            Err(Error::JvmInternal(
+                "failed to get the ID of a reflected field".into(),
+            ))
+        }
+    }
+}
+
+/// A public field.
+struct FieldInfo {
+    field: FieldPtr,
+    ty: Type,
+}
+
+/// What a method is invoked on.
+#[derive(Clone, Copy)]
+enum Receiver {
+    Instance(ObjectPtr),
+    Class(ObjectPtr),
+}
+
+/// A Java class whose public constructors, methods and fields are looked up by name at runtime,
+/// for classes that don't have a [`java_package`](`crate::java_package`) mirror because they are not known at build time.
+///
+/// Members are found with reflection the first time they are used, then cached, so later calls
+/// cost about the same as calls through generated mirrors. Overloads are chosen from the arguments
+/// like `javac` would, except that [`DynValue`] scalars are never boxed.
+///
+/// Get one with [`DynClass::for_name`] or, inside an operation, with [`Jvm::dyn_class`].
+/// Handles to the same class share their caches, which live until the last handle (and the last
+/// [`DynObject`] of the class) is dropped, so duchess does not prevent the class from being unloaded.
+#[derive(Clone)]
+pub struct DynClass {
+    inner: Arc<DynClassInner>,
+}
+
+/// Members are cached by name and whether they are static.
+type MemberKey = (String, bool);
+
+struct DynClassInner {
+    name: String,
+    class: Java<Class>,
+    constructors: OnceCell<Arc<[Executable]>>,
+    methods: Mutex<HashMap<MemberKey, Arc<[Executable]>>>,
+    fields: Mutex<HashMap<MemberKey, Arc<FieldInfo>>>,
+}
+
+/// Classes that currently have a [`DynClass`], by name, so that their members are only resolved once.
+/// A name can have several entries when classes are loaded by different class loaders.
+/// The entries are weak, so that they don't keep the classes alive.
+static CLASSES: Lazy<Mutex<HashMap<String, Vec<Weak<DynClassInner>>>>> =
+    Lazy::new(Default::default);
+
+impl<'jvm> Jvm<'jvm> {
+    /// Looks up the class named `name`, either as `java.util.ArrayList` or `java/util/ArrayList`,
+    /// for calls through reflection. See [`DynClass`].
+    pub fn dyn_class(&mut self, name: &str) -> LocalResult<'jvm, DynClass> {
+        let jni_name =
+            CString::new(name.replace('.', "/")).map_err(|_| Error::NoMatchingMember {
+                class: name.to_string(),
+                member: String::new(),
+                reason: "class names cannot contain nul characters".to_string(),
+            })?;
+        let class = find_class(self, &jni_name)?;
+        DynClass::intern(self, &class)
+    }
+}
+
+impl DynClass {
+    /// Looks up the class named `name`, either as `java.util.ArrayList` or `java/util/ArrayList`.
+    /// This is the operation form of [`Jvm::dyn_class`], for use outside of other operations.
+    pub fn for_name(name: &str) -> impl for<'jvm> JvmOp<Output<'jvm> = DynClass> + '_ {
+        DynOp::new(move |jvm: &mut Jvm<'_>| jvm.dyn_class(name))
+    }
+
+    fn intern<'jvm>(jvm: &mut Jvm<'jvm>, class: &Class) -> LocalResult<'jvm, DynClass> {
+        let name: String = class.get_name().assert_not_null().execute_with(jvm)?;
+        let mut classes = CLASSES.lock().unwrap();
+        let same_name = classes.entry(name.clone()).or_default();
+        same_name.retain(|known| known.strong_count() > 0);
+        let known = same_name
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|known| is_same_object(jvm, &known.class, class));
+        if let Some(inner) = known {
+            return Ok(DynClass { inner });
+        }
+
+        let dyn_class = DynClass {
+            inner: Arc::new(DynClassInner {
+                name,
+                class: jvm.global(class),
+                constructors: OnceCell::new(),
+                methods: Mutex::default(),
+                fields: Mutex::default(),
+            }),
+        };
+        same_name.push(Arc::downgrade(&dyn_class.inner));
+        Ok(dyn_class)
+    }
+
+    /// The binary name of the class, like `java.util.ArrayList`.
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    pub fn as_java(&self) -> &Java<Class> {
+        &self.inner.class
+    }
+
+    /// Creates an instance with the public constructor that best matches `args`.
+    pub fn new_instance<'a>(
+        &'a self,
+        args: &'a [DynValue],
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = DynObject> + 'a {
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            let constructors = self.inner.constructors.get_or_try_init(|| {
+                let class: &Class = &self.inner.class;
+                let constructors = class.get_constructors().do_jni(jvm)?;
+                let constructors = filter_map_elements(jvm, constructors, |jvm, constructor| {
+                    Executable::from_constructor(jvm, constructor).map(Some)
+                })?;
+                Ok::<_, Error<_>>(Arc::from(constructors))
+            })?;
+            let args = args
+                .iter()
+                .map(|arg| Arg::new(jvm, arg))
+                .collect::<LocalResult<'_, Vec<_>>>()?;
+            let constructor = self.select(jvm, "<init>", constructors, &args)?;
+
+            let class = self.inner.class.as_raw();
+            let values = jni_values(constructor, &args);
+            // SAFETY: the constructor belongs to `class` and `values` matches its parameters
+            let object: Option<Local<'_, Object>> = unsafe {
+                jvm.env().invoke(
+                    |env| env.NewObjectA,
+                    |env, f| {
+                        f(
+                            env,
+                            class.as_ptr(),
+                            constructor.method.as_ptr(),
+                            values.as_ptr(),
+                        )
+                    },
+                )?
+            };
+            let object = object.ok_or_else(|| {
+                Error::JvmInternal(format!("constructing a `{}` returned null", self.name()))
+            })?;
+            Ok(DynObject {
+                object: Arc::new(jvm.global(&*object)),
+                class: Arc::new(OnceCell::with_value(self.clone())),
+            })
+        })
+    }
+
+    /// Calls the public static method named `name` that best matches `args`.
+    pub fn call_static<'a>(
+        &'a self,
+        name: &'a str,
+        args: &'a [DynValue],
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = DynValue> + 'a {
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            self.invoke(jvm, Receiver::Class(self.inner.class.as_raw()), name, args)
+        })
+    }
+
+    /// Reads the public static field named `name`.
+    pub fn get_static_field<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = DynValue> + 'a {
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            self.get(jvm, Receiver::Class(self.inner.class.as_raw()), name)
+        })
+    }
+
+    /// Writes the public static field named `name`.
+    pub fn set_static_field<'a>(
+        &'a self,
+        name: &'a str,
+        value: impl Into<DynValue>,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = ()> + 'a {
+        let value = value.into();
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            self.set(
+                jvm,
+                Receiver::Class(self.inner.class.as_raw()),
+                name,
+                &value,
+            )
+        })
+    }
+
+    fn no_match(
+        &self,
+        member: &str,
+        reason: impl Into<String>,
+    ) -> Error<Local<'static, java::lang::Throwable>> {
+        Error::NoMatchingMember {
+            class: self.name().to_string(),
+            member: member.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Picks the most specific of `candidates` that accepts `args`.
+    fn select<'e, 'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        member: &str,
+        candidates: &'e [Executable],
+        args: &[Arg<'_, 'jvm>],
+    ) -> LocalResult<'jvm, &'e Executable> {
+        if candidates.is_empty() {
+            return Err(self.no_match(member, "there is no public member with this name"));
+        }
+
+        let applicable: Vec<&Executable> = candidates
+            .iter()
+            .filter(|candidate| candidate.accepts(args, jvm))
+            .collect();
+        if applicable.is_empty() {
+            return Err(self.no_match(
+                member,
+                format!("no overload accepts the {} given arguments", args.len()),
+            ));
+        }
+
+        applicable
+            .iter()
+            .find(|candidate| {
+                applicable
+                    .iter()
+                    .all(|other| candidate.is_more_specific_than(other, jvm))
+            })
+            .copied()
+            .ok_or_else(|| {
+                self.no_match(member, "the arguments match several overloads equally well")
+            })
+    }
+
+    fn methods<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        name: &str,
+        is_static: bool,
+    ) -> LocalResult<'jvm, Arc<[Executable]>> {
+        let key = (name.to_string(), is_static);
+        if let Some(methods) = self.inner.methods.lock().unwrap().get(&key) {
+            return Ok(methods.clone());
+        }
+
+        let class: &Class = &self.inner.class;
+        let reflected = class.get_methods().do_jni(jvm)?;
+        let methods = filter_map_elements(jvm, reflected, |jvm, method: &Method| {
+            let method_name: String = method.get_name().assert_not_null().execute_with(jvm)?;
+            let modifiers = method.get_modifiers().do_jni(jvm)?;
+            // Bridge methods duplicate a real method with erased parameter types
+            if method_name == name
+                && (modifiers & STATIC != 0) == is_static
+                && !method.is_bridge().do_jni(jvm)?
+            {
+                Ok(Some(Executable::from_method(jvm, method)?))
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        let methods: Arc<[Executable]> = methods.into();
+        self.inner
+            .methods
+            .lock()
+            .unwrap()
+            .insert(key, methods.clone());
+        Ok(methods)
+    }
+
+    fn field<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        name: &str,
+        is_static: bool,
+    ) -> LocalResult<'jvm, Arc<FieldInfo>> {
+        let key = (name.to_string(), is_static);
+        if let Some(field) = self.inner.fields.lock().unwrap().get(&key) {
+            return Ok(field.clone());
+        }
+
+        let class: &Class = &self.inner.class;
+        let reflected = class.get_fields().do_jni(jvm)?;
+        let fields = filter_map_elements(jvm, reflected, |jvm, field: &Field| {
+            let field_name: String = field.get_name().assert_not_null().execute_with(jvm)?;
+            let modifiers = field.get_modifiers().do_jni(jvm)?;
+            if field_name == name && (modifiers & STATIC != 0) == is_static {
+                let ty = field.get_type().assert_not_null().do_jni(jvm)?;
+                Ok(Some(FieldInfo {
+                    field: from_reflected_field(jvm, field.as_raw())?,
+                    ty: Type::of(jvm, &ty)?,
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
+        let Some(info) = fields.into_iter().next() else {
+            return Err(self.no_match(name, "there is no public field with this name"));
+        };
+        let info = Arc::new(info);
+        self.inner.fields.lock().unwrap().insert(key, info.clone());
+        Ok(info)
+    }
+
+    fn invoke<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        receiver: Receiver,
+        name: &str,
+        args: &[DynValue],
+    ) -> LocalResult<'jvm, DynValue> {
+        let methods = self.methods(jvm, name, matches!(receiver, Receiver::Class(_)))?;
+        let args = args
+            .iter()
+            .map(|arg| Arg::new(jvm, arg))
+            .collect::<LocalResult<'_, Vec<_>>>()?;
+        let method = self.select(jvm, name, &methods, &args)?;
+        let values = jni_values(method, &args);
+        let env = jvm.env();
+
+        macro_rules! call {
+            ($t:ty, $instance:ident, $static:ident) => {{
+                // SAFETY: the method belongs to this class and `values` matches its parameters
+                let value: $t = unsafe {
+                    match receiver {
+                        Receiver::Instance(object) => env.invoke(
+                            |env| env.$instance,
+                            |env, f| {
+                                f(
+                                    env,
+                                    object.as_ptr(),
+                                    method.method.as_ptr(),
+                                    values.as_ptr(),
+                                )
+                            },
+                        )?,
+                        Receiver::Class(class) => env.invoke(
+                            |env| env.$static,
+                            |env, f| {
+                                f(env, class.as_ptr(), method.method.as_ptr(), values.as_ptr())
+                            },
+                        )?,
+                    }
+                };
+                value
+            }};
+        }
+
+        Ok(match &method.ret {
+            Type::Void => {
+                call!((), CallVoidMethodA, CallStaticVoidMethodA);
+                DynValue::Null
+            }
+            Type::Primitive(Primitive::Boolean) => {
+                DynValue::Boolean(call!(bool, CallBooleanMethodA, CallStaticBooleanMethodA))
+            }
+            Type::Primitive(Primitive::Byte) => {
+                DynValue::Byte(call!(i8, CallByteMethodA, CallStaticByteMethodA))
+            }
+            Type::Primitive(Primitive::Char) => {
+                DynValue::Char(call!(u16, CallCharMethodA, CallStaticCharMethodA))
+            }
+            Type::Primitive(Primitive::Short) => {
+                DynValue::Short(call!(i16, CallShortMethodA, CallStaticShortMethodA))
+            }
+            Type::Primitive(Primitive::Int) => {
+                DynValue::Int(call!(i32, CallIntMethodA, CallStaticIntMethodA))
+            }
+            Type::Primitive(Primitive::Long) => {
+                DynValue::Long(call!(i64, CallLongMethodA, CallStaticLongMethodA))
+            }
+            Type::Primitive(Primitive::Float) => {
+                DynValue::Float(call!(f32, CallFloatMethodA, CallStaticFloatMethodA))
+            }
+            Type::Primitive(Primitive::Double) => {
+                DynValue::Double(call!(f64, CallDoubleMethodA, CallStaticDoubleMethodA))
+            }
+            Type::Reference(_) => {
+                let object = call!(
+                    Option<Local<'_, Object>>,
+                    CallObjectMethodA,
+                    CallStaticObjectMethodA
+                );
+                DynValue::from_object(jvm, object)?
+            }
+        })
+    }
+
+    fn get<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        receiver: Receiver,
+        name: &str,
+    ) -> LocalResult<'jvm, DynValue> {
+        let field = self.field(jvm, name, matches!(receiver, Receiver::Class(_)))?;
+        let env = jvm.env();
+
+        macro_rules! get {
+            ($t:ty, $instance:ident, $static:ident) => {{
+                // SAFETY: the field belongs to this class and has type `$t`
+                let value: $t = unsafe {
+                    match receiver {
+                        Receiver::Instance(object) => env.invoke(
+                            |env| env.$instance,
+                            |env, f| f(env, object.as_ptr(), field.field.as_ptr()),
+                        )?,
+                        Receiver::Class(class) => env.invoke(
+                            |env| env.$static,
+                            |env, f| f(env, class.as_ptr(), field.field.as_ptr()),
+                        )?,
+                    }
+                };
+                value
+            }};
+        }
+
+        Ok(match &field.ty {
+            Type::Void => unreachable!("fields cannot be `void`"),
+            Type::Primitive(Primitive::Boolean) => {
+                DynValue::Boolean(get!(bool, GetBooleanField, GetStaticBooleanField))
+            }
+            Type::Primitive(Primitive::Byte) => {
+                DynValue::Byte(get!(i8, GetByteField, GetStaticByteField))
+            }
+            Type::Primitive(Primitive::Char) => {
+                DynValue::Char(get!(u16, GetCharField, GetStaticCharField))
+            }
+            Type::Primitive(Primitive::Short) => {
+                DynValue::Short(get!(i16, GetShortField, GetStaticShortField))
+            }
+            Type::Primitive(Primitive::Int) => {
+                DynValue::Int(get!(i32, GetIntField, GetStaticIntField))
+            }
+            Type::Primitive(Primitive::Long) => {
+                DynValue::Long(get!(i64, GetLongField, GetStaticLongField))
+            }
+            Type::Primitive(Primitive::Float) => {
+                DynValue::Float(get!(f32, GetFloatField, GetStaticFloatField))
+            }
+            Type::Primitive(Primitive::Double) => {
+                DynValue::Double(get!(f64, GetDoubleField, GetStaticDoubleField))
+            }
+            Type::Reference(_) => {
+                let object = get!(
+                    Option<Local<'_, Object>>,
+                    GetObjectField,
+                    GetStaticObjectField
+                );
+                DynValue::from_object(jvm, object)?
+            }
+        })
+    }
+
+    fn set<'jvm>(
+        &self,
+        jvm: &mut Jvm<'jvm>,
+        receiver: Receiver,
+        name: &str,
+        value: &DynValue,
+    ) -> LocalResult<'jvm, ()> {
+        let field = self.field(jvm, name, matches!(receiver, Receiver::Class(_)))?;
+        let arg = Arg::new(jvm, value)?;
+        if !field.ty.accepts(&arg, jvm) {
+            return Err(self.no_match(
+                name,
+                format!("the field cannot be assigned a `{}`", value.type_name()),
+            ));
+        }
+        let value = arg.to_jni_value(&field.ty);
+        let env = jvm.env();
+
+        macro_rules! set {
+            ($field:ident, $instance:ident, $static:ident) => {
+                // SAFETY: the field belongs to this class and `value` was converted to its type
+                unsafe {
+                    match receiver {
+                        Receiver::Instance(object) => env.invoke::<_, ()>(
+                            |env| env.$instance,
+                            |env, f| f(env, object.as_ptr(), field.field.as_ptr(), value.$field),
+                        )?,
+                        Receiver::Class(class) => env.invoke::<_, ()>(
+                            |env| env.$static,
+                            |env, f| f(env, class.as_ptr(), field.field.as_ptr(), value.$field),
+                        )?,
+                    }
+                }
+            };
+        }
+
+        match &field.ty {
+            Type::Void => unreachable!("fields cannot be `void`"),
+            Type::Primitive(Primitive::Boolean) => set!(z, SetBooleanField, SetStaticBooleanField),
+            Type::Primitive(Primitive::Byte) => set!(b, SetByteField, SetStaticByteField),
+            Type::Primitive(Primitive::Char) => set!(c, SetCharField, SetStaticCharField),
+            Type::Primitive(Primitive::Short) => set!(s, SetShortField, SetStaticShortField),
+            Type::Primitive(Primitive::Int) => set!(i, SetIntField, SetStaticIntField),
+            Type::Primitive(Primitive::Long) => set!(j, SetLongField, SetStaticLongField),
+            Type::Primitive(Primitive::Float) => set!(f, SetFloatField, SetStaticFloatField),
+            Type::Primitive(Primitive::Double) => set!(d, SetDoubleField, SetStaticDoubleField),
+            Type::Reference(_) => set!(l, SetObjectField, SetStaticObjectField),
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for DynClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DynClass").field(&self.name()).finish()
+    }
+}
+
+fn jni_values(executable: &Executable, args: &[Arg<'_, '_>]) -> Vec<jvalue> {
+    executable
+        .params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| arg.to_jni_value(param))
+        .collect()
+}
+
+/// A Java object whose public methods and fields are looked up by name at runtime, see [`DynClass`].
+#[derive(Clone)]
+pub struct DynObject {
+    object: Arc<Java<Object>>,
+    class: Arc<OnceCell<DynClass>>,
+}
+
+impl DynObject {
+    pub fn as_java(&self) -> &Java<Object> {
+        &self.object
+    }
+
+    /// The runtime class of the object.
+    pub fn class(&self) -> impl for<'jvm> JvmOp<Output<'jvm> = DynClass> + '_ {
+        DynOp::new(move |jvm: &mut Jvm<'_>| self.dyn_class(jvm).cloned())
+    }
+
+    /// Calls the public instance method named `name` that best matches `args`.
+    pub fn call<'a>(
+        &'a self,
+        name: &'a str,
+        args: &'a [DynValue],
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = DynValue> + 'a {
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            let class = self.dyn_class(jvm)?;
+            class.invoke(jvm, Receiver::Instance(self.object.as_raw()), name, args)
+        })
+    }
+
+    /// Reads the public instance field named `name`.
+    pub fn get_field<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = DynValue> + 'a {
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            let class = self.dyn_class(jvm)?;
+            class.get(jvm, Receiver::Instance(self.object.as_raw()), name)
+        })
+    }
+
+    /// Writes the public instance field named `name`.
+    pub fn set_field<'a>(
+        &'a self,
+        name: &'a str,
+        value: impl Into<DynValue>,
+    ) -> impl for<'jvm> JvmOp<Output<'jvm> = ()> + 'a {
+        let value = value.into();
+        DynOp::new(move |jvm: &mut Jvm<'_>| {
+            let class = self.dyn_class(jvm)?;
+            class.set(jvm, Receiver::Instance(self.object.as_raw()), name, &value)
+        })
+    }
+
+    fn dyn_class<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, &DynClass> {
+        self.class.get_or_try_init(|| {
+            let object = self.object.as_raw();
+            // SAFETY: `object` is a live reference, so `GetObjectClass` returns its non-null class
+            let class: Option<Local<'_, Class>> = unsafe {
+                jvm.env()
+                    .invoke(|env| env.GetObjectClass, |env, f| f(env, object.as_ptr()))?
+            };
+            let class = class.ok_or_else(|| Error::JvmInternal("object has no class".into()))?;
+            DynClass::intern(jvm, &class)
+        })
+    }
+}
+
+impl fmt::Debug for DynObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynObject")
+            .field("class", &self.class.get().map(DynClass::name))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> From<Java<T>> for DynObject
+where
+    T: crate::semver_unstable::Upcast<Object>,
+{
+    fn from(object: Java<T>) -> Self {
+        DynObject {
+            object: Arc::new(object.upcast()),
+            class: Arc::new(OnceCell::new()),
+        }
+    }
+}
+
+impl DynValue {
+    fn from_object<'jvm>(
+        jvm: &mut Jvm<'jvm>,
+        object: Option<Local<'jvm, Object>>,
+    ) -> LocalResult<'jvm, DynValue> {
+        let Some(object) = object else {
+            return Ok(DynValue::Null);
+        };
+        match (&object).try_downcast::<java::lang::String>().do_jni(jvm)? {
+            Ok(string) => Ok(DynValue::String(string.into_rust(jvm)?)),
+            Err(object) => Ok(DynValue::Object(jvm.global::<Object>(object).into())),
+        }
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        Some(match self {
+            DynValue::Boolean(_) => Primitive::Boolean,
+            DynValue::Byte(_) => Primitive::Byte,
+            DynValue::Char(_) => Primitive::Char,
+            DynValue::Short(_) => Primitive::Short,
+            DynValue::Int(_) => Primitive::Int,
+            DynValue::Long(_) => Primitive::Long,
+            DynValue::Float(_) => Primitive::Float,
+            DynValue::Double(_) => Primitive::Double,
+            DynValue::Null | DynValue::String(_) | DynValue::Object(_) => return None,
+        })
+    }
+
+    /// The value of an integral scalar.
+    fn integer(&self) -> Option<i64> {
+        match *self {
+            DynValue::Byte(b) => Some(b.into()),
+            DynValue::Char(c) => Some(c.into()),
+            DynValue::Short(s) => Some(s.into()),
+            DynValue::Int(i) => Some(i.into()),
+            DynValue::Long(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Converts a scalar to the primitive type `to`, which it must widen to.
+    fn to_jni_value(&self, to: Primitive) -> jvalue {
+        match (to, self) {
+            (Primitive::Boolean, DynValue::Boolean(b)) => b.into_jni_value(),
+            (Primitive::Float, DynValue::Float(f)) => f.into_jni_value(),
+            (Primitive::Double, DynValue::Float(f)) => f64::from(*f).into_jni_value(),
+            (Primitive::Double, DynValue::Double(d)) => d.into_jni_value(),
+            _ => {
+                let i = self.integer().expect("only integers remain after widening");
+                match to {
+                    Primitive::Byte => (i as i8).into_jni_value(),
+                    Primitive::Char => (i as u16).into_jni_value(),
+                    Primitive::Short => (i as i16).into_jni_value(),
+                    Primitive::Int => (i as i32).into_jni_value(),
+                    Primitive::Long => i.into_jni_value(),
+                    Primitive::Float => (i as f32).into_jni_value(),
+                    Primitive::Double => (i as f64).into_jni_value(),
+                    Primitive::Boolean => unreachable!("only booleans widen to boolean"),
+                }
+            }
+        }
+    }
+
+    fn type_name(&self) -> String {
+        match self {
+            DynValue::Null => "null".to_string(),
+            DynValue::Boolean(_) => "boolean".to_string(),
+            DynValue::Byte(_) => "byte".to_string(),
+            DynValue::Char(_) => "char".to_string(),
+            DynValue::Short(_) => "short".to_string(),
+            DynValue::Int(_) => "int".to_string(),
+            DynValue::Long(_) => "long".to_string(),
+            DynValue::Float(_) => "float".to_string(),
+            DynValue::Double(_) => "double".to_string(),
+            DynValue::String(_) => "java.lang.String".to_string(),
+            DynValue::Object(o) => match o.class.get() {
+                Some(class) => class.name().to_string(),
+                None => "java.lang.Object".to_string(),
+            },
+        }
+    }
+}
+
+macro_rules! dyn_value_scalars {
+    ($($rust:ty: $variant:ident $java:literal,)*) => {
+        $(
+            impl From<$rust> for DynValue {
+                fn from(value: $rust) -> Self {
+                    DynValue::$variant(value)
+                }
+            }
+
+            impl IntoRust<$rust> for DynValue {
+                fn into_rust<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, $rust> {
+                    match self {
+                        DynValue::$variant(value) => Ok(value),
+                        other => Err(Error::DynTypeMismatch {
+                            expected: $java,
+                            found: other.type_name(),
+                        }),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+dyn_value_scalars! {
+    bool: Boolean "boolean",
+    i8: Byte "byte",
+    u16: Char "char",
+    i16: Short "short",
+    i32: Int "int",
+    i64: Long "long",
+    f32: Float "float",
+    f64: Double "double",
+    String: String "java.lang.String",
+    DynObject: Object "java.lang.Object",
+}
+
+impl From<&str> for DynValue {
+    fn from(value: &str) -> Self {
+        DynValue::String(value.to_string())
+    }
+}
+
+impl<T> From<Java<T>> for DynValue
+where
+    T: crate::semver_unstable::Upcast<Object>,
+{
+    fn from(object: Java<T>) -> Self {
+        DynValue::Object(object.into())
+    }
+}
+
+/// `None` becomes [`DynValue::Null`].
+impl<T: Into<DynValue>> From<Option<T>> for DynValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(DynValue::Null, Into::into)
+    }
+}
+
+identity_rust_op! {
+    DynClass,
+    DynObject,
+    DynValue,
+}
+
+/// [`DynValue::Null`] becomes `None`.
+impl<R> IntoRust<Option<R>> for DynValue
+where
+    DynValue: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, Option<R>> {
+        match self {
+            DynValue::Null => Ok(None),
+            value => value.into_rust(jvm).map(Some),
+        }
+    }
+}
+
+/// The operation returned by the methods of [`DynClass`] and [`DynObject`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; F: Copy)]
+struct DynOp<F, R>
+where
+    F: Clone,
+{
+    f: F,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<F, R> DynOp<F, R>
+where
+    F: Clone + for<'jvm> FnOnce(&mut Jvm<'jvm>) -> LocalResult<'jvm, R>,
+{
+    fn new(f: F) -> Self {
+        Self {
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<F, R> JvmOp for DynOp<F, R>
+where
+    F: Clone + for<'jvm> FnOnce(&mut Jvm<'jvm>) -> LocalResult<'jvm, R>,
+{
+    type Output<'jvm> = R;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, Self::Output<'jvm>> {
+        (self.f)(jvm)
+    }
+}
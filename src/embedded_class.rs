@@ -0,0 +1,180 @@
+use std::{
+    ffi::CStr,
+    sync::{Arc, RwLock},
+};
+
+use once_cell::sync::{Lazy, OnceCell};
+
+use crate::{
+    class_loader,
+    java::lang::{Class, ClassLoader, Throwable},
+    jvm::JavaObjectExt,
+    Error, Java, Jvm, JvmOp, Local,
+};
+
+/// The bytecode of a Java class that is compiled into the Rust binary, such as the `Shim$` classes
+/// that `duchess-build-rs` generates for Rust implementations of Java interfaces.
+///
+/// Register embedded classes with [`register_embedded_classes`] (or
+/// [`JvmBuilder::embed_classes`](`crate::jvm::JvmBuilder::embed_classes`) when duchess starts the JVM)
+/// and they are defined with JNI `DefineClass` the first time they are looked up, so no `CLASSPATH`
+/// entry is needed for them at deploy time. See [`embedded_classes!`](`crate::embedded_classes`).
+#[derive(Copy, Clone, Debug)]
+pub struct EmbeddedClass {
+    jni_name: &'static str,
+    bytes: &'static [u8],
+}
+
+impl EmbeddedClass {
+    /// Creates an embedded class from its JNI name (e.g. `duchess/Shim$java$lang$Runnable`)
+    /// and the contents of its `.class` file.
+    pub const fn new(jni_name: &'static str, bytes: &'static [u8]) -> Self {
+        Self { jni_name, bytes }
+    }
+
+    /// The JNI name of this class, e.g. `duchess/Shim$java$lang$Runnable`.
+    pub fn jni_name(&self) -> &'static str {
+        self.jni_name
+    }
+}
+
+/// Expands to the classes that `duchess-build-rs` compiled for the current crate, as a
+/// `&'static [EmbeddedClass]` suitable for [`register_embedded_classes`](`crate::register_embedded_classes`)
+/// or [`JvmBuilder::embed_classes`](`crate::jvm::JvmBuilder::embed_classes`).
+///
+/// ```ignore
+/// duchess::Jvm::builder()
+///     .embed_classes(duchess::embedded_classes!())
+///     .try_launch()?;
+/// ```
+///
+/// A library loaded by a JVM that duchess did not start registers them directly instead:
+///
+/// ```ignore
+/// duchess::register_embedded_classes(duchess::embedded_classes!());
+/// ```
+#[macro_export]
+macro_rules! embedded_classes {
+    () => {
+        include!(concat!(
+            env!("DUCHESS_OUT_DIR"),
+            "/duchess_embedded_classes.rs"
+        ))
+    };
+}
+
+struct Embedded {
+    class: EmbeddedClass,
+    defined: OnceCell<Java<Class>>,
+}
+
+impl Embedded {
+    fn new(class: EmbeddedClass) -> Self {
+        Self {
+            class,
+            defined: OnceCell::new(),
+        }
+    }
+}
+
+/// Classes that duchess itself relies on, compiled from the `java` directory by our build script.
+const BUILTIN_CLASSES: &[EmbeddedClass] = {
+    use crate as duchess;
+    include!(concat!(
+        env!("DUCHESS_OUT_DIR"),
+        "/duchess_embedded_classes.rs"
+    ))
+};
+
+static EMBEDDED_CLASSES: Lazy<RwLock<Vec<Arc<Embedded>>>> = Lazy::new(|| {
+    RwLock::new(
+        BUILTIN_CLASSES
+            .iter()
+            .map(|&class| Arc::new(Embedded::new(class)))
+            .collect(),
+    )
+});
+
+/// Registers classes compiled into the Rust binary, which are defined on first use when
+/// they cannot be found on the classpath. See [`EmbeddedClass`].
+///
+/// Unlike [`JvmBuilder::embed_classes`](`crate::jvm::JvmBuilder::embed_classes`), this works whether
+/// or not duchess started the JVM, e.g. from a native library that Java loaded with `System.loadLibrary`,
+/// and it can be called any number of times. A class whose JNI name is already registered is ignored.
+pub fn register_embedded_classes(classes: &[EmbeddedClass]) {
+    let mut embedded = EMBEDDED_CLASSES.write().unwrap();
+    for &class in classes {
+        if !embedded
+            .iter()
+            .any(|known| known.class.jni_name == class.jni_name)
+        {
+            embedded.push(Arc::new(Embedded::new(class)));
+        }
+    }
+}
+
+/// Defines the embedded class named `jni_name` if it has not been defined yet,
+/// returning `None` if no such class was embedded.
+pub(crate) fn define_embedded_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    jni_name: &CStr,
+) -> crate::LocalResult<'jvm, Option<Local<'jvm, Class>>> {
+    let embedded = EMBEDDED_CLASSES
+        .read()
+        .unwrap()
+        .iter()
+        .find(|embedded| embedded.class.jni_name.as_bytes() == jni_name.to_bytes())
+        .cloned();
+    let Some(embedded) = embedded else {
+        return Ok(None);
+    };
+
+    let class = embedded
+        .defined
+        .get_or_try_init::<_, Error<Local<Throwable>>>(|| {
+            let class = define_class(jvm, jni_name, embedded.class.bytes)?;
+            Ok(jvm.global(&class))
+        })?;
+    Ok(Some(jvm.local(class)))
+}
+
+fn define_class<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    jni_name: &CStr,
+    bytes: &[u8],
+) -> crate::LocalResult<'jvm, Local<'jvm, Class>> {
+    let Ok(len) = bytes.len().try_into() else {
+        return Err(Error::SliceTooLong(bytes.len()));
+    };
+
+    // Define the class where the application's classes live, so it can see the interfaces it implements
+    let loader = match class_loader::fallback_class_loader(jvm) {
+        Some(loader) => loader,
+        None => ClassLoader::get_system_class_loader()
+            .do_jni(jvm)?
+            .ok_or_else(|| Error::JvmInternal("no system class loader".to_string()))?,
+    };
+
+    let class: Option<Local<Class>> = unsafe {
+        // SAFETY: jni_name is nul-terminated and bytes is valid for len bytes
+        jvm.env().invoke(
+            |env| env.DefineClass,
+            |env, f| {
+                f(
+                    env,
+                    jni_name.as_ptr(),
+                    loader.as_raw().as_ptr(),
+                    bytes.as_ptr().cast(),
+                    len,
+                )
+            },
+        )
+    }?;
+
+    class.ok_or_else(|| {
+        Error::JvmInternal(format!(
+            "failed to define class `{}`",
+            jni_name.to_string_lossy()
+        ))
+    })
+}
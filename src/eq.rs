@@ -0,0 +1,155 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    java::lang::{Object, System},
+    jvm::JavaObjectExt,
+    AsJRef, IntoRust, Jvm, JvmOp,
+};
+
+/// Wraps a Java reference so that it is compared and hashed by object identity, like `==` and
+/// `System.identityHashCode` in Java.
+///
+/// The `PartialEq` and `Hash` impls make their own call into the JVM, attaching the current thread if needed,
+/// so they are meant for global references such as [`Java<T>`][`crate::Java`] (e.g. as `HashMap` keys).
+/// Inside a [`JvmOp`], where a [`Local<T>`][`crate::Local`] lives, use [`Identity::identity_eq`] and
+/// [`Identity::identity_hash`] with the operation's `Jvm` instead.
+///
+/// # Panics
+///
+/// **`==` and hashing panic if the JVM call fails**: if the reference is null, the JVM cannot be reached,
+/// or the thread is already executing a [`JvmOp`]. A panic while a `HashMap` is inserting or looking up
+/// a key propagates out of that call, so when failures are possible, compare and hash with the fallible
+/// [`Identity::identity_eq`] and [`Identity::identity_hash`] up front.
+#[derive(Clone, Copy, Default)]
+pub struct Identity<R>(pub R);
+
+/// Wraps a Java reference so that `PartialEq`, `Hash`, `Debug` and `Display` use the object's own `equals`,
+/// `hashCode` and `toString` methods.
+///
+/// The trait impls make their own call into the JVM, attaching the current thread if needed,
+/// so they are meant for global references such as [`Java<T>`][`crate::Java`] (e.g. as `HashMap` keys).
+/// Inside a [`JvmOp`], where a [`Local<T>`][`crate::Local`] lives, use [`Semantic::semantic_eq`] and
+/// [`Semantic::semantic_hash`] with the operation's `Jvm` instead.
+///
+/// # Panics
+///
+/// **`==` and hashing panic if the JVM call fails**: if the reference is null, the Java method throws,
+/// the JVM cannot be reached, or the thread is already executing a [`JvmOp`]. A panic while a `HashMap`
+/// is inserting or looking up a key propagates out of that call, so when failures are possible (e.g. for
+/// objects whose `equals` or `hashCode` may throw), compare and hash with the fallible
+/// [`Semantic::semantic_eq`] and [`Semantic::semantic_hash`] up front. Formatting doesn't panic: it writes
+/// `<failed to call toString: ...>` with the error instead.
+#[derive(Clone, Copy, Default)]
+pub struct Semantic<R>(pub R);
+
+fn call_jvm<R>(
+    what: &str,
+    op: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+) -> R {
+    match Jvm::with(op) {
+        Ok(r) => r,
+        Err(e) => panic!("failed to {what}: {e}"),
+    }
+}
+
+impl<R: AsJRef<Object>> Identity<R> {
+    /// Returns whether both references point to the same Java object, using the `Jvm` of an enclosing operation.
+    pub fn identity_eq<'jvm>(
+        &self,
+        other: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, bool> {
+        let this = self.0.as_jref()?.as_raw();
+        let other = other.0.as_jref()?.as_raw();
+        // SAFETY: both are valid references; `IsSameObject` doesn't throw
+        let same = unsafe {
+            jvm.env().invoke_unchecked(
+                |env| env.IsSameObject,
+                |env, f| f(env, this.as_ptr(), other.as_ptr()),
+            )
+        };
+        Ok(same == jni_sys::JNI_TRUE)
+    }
+
+    /// Returns `System.identityHashCode` of the object, using the `Jvm` of an enclosing operation.
+    pub fn identity_hash<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, i32> {
+        System::identity_hash_code(self.0.as_jref()?).do_jni(jvm)
+    }
+}
+
+impl<R: AsJRef<Object>> PartialEq for Identity<R> {
+    fn eq(&self, other: &Self) -> bool {
+        call_jvm("compare Java objects by identity", |jvm| {
+            self.identity_eq(other, jvm)
+        })
+    }
+}
+
+impl<R: AsJRef<Object>> Eq for Identity<R> {}
+
+impl<R: AsJRef<Object>> Hash for Identity<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        call_jvm("compute `System.identityHashCode`", |jvm| {
+            self.identity_hash(jvm)
+        })
+        .hash(state)
+    }
+}
+
+impl<R: AsJRef<Object>> Semantic<R> {
+    /// Calls `equals` on the object with `other`, using the `Jvm` of an enclosing operation.
+    pub fn semantic_eq<'jvm>(
+        &self,
+        other: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, bool> {
+        self.0.as_jref()?.equals(other.0.as_jref()?).do_jni(jvm)
+    }
+
+    /// Calls `hashCode` on the object, using the `Jvm` of an enclosing operation.
+    pub fn semantic_hash<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, i32> {
+        self.0.as_jref()?.hash_code().do_jni(jvm)
+    }
+}
+
+impl<R: AsJRef<Object>> PartialEq for Semantic<R> {
+    fn eq(&self, other: &Self) -> bool {
+        call_jvm("call `equals`", |jvm| self.semantic_eq(other, jvm))
+    }
+}
+
+impl<R: AsJRef<Object>> Eq for Semantic<R> {}
+
+impl<R: AsJRef<Object>> Hash for Semantic<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        call_jvm("call `hashCode`", |jvm| self.semantic_hash(jvm)).hash(state)
+    }
+}
+
+impl<R: AsJRef<Object>> fmt::Display for Semantic<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string: crate::Result<String> = Jvm::with(|jvm| {
+            let string = self
+                .0
+                .as_jref()?
+                .to_string()
+                .assert_not_null()
+                .do_jni(jvm)?;
+            string.into_rust(jvm)
+        });
+        match string {
+            Ok(string) => f.write_str(&string),
+            // `format!` and `to_string` panic if `Display` fails, so describe the failure instead
+            Err(err) => write!(f, "<failed to call toString: {err}>"),
+        }
+    }
+}
+
+impl<R: AsJRef<Object>> fmt::Debug for Semantic<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -1,12 +1,20 @@
 use std::{
+    ffi::CStr,
     fmt::{Debug, Display},
     result,
 };
 
+use once_cell::sync::OnceCell;
 use thiserror::Error;
 
-use crate::AsJRef;
-use crate::{java::lang::Throwable, Java, Jvm, JvmOp, Local};
+use crate::{
+    find::find_constructor,
+    java::lang::{RuntimeException, Throwable},
+    jvm::JavaObjectExt,
+    raw::MethodPtr,
+    Java, JavaObject, Jvm, JvmOp, Local,
+};
+use crate::{AsJRef, NullJRef};
 
 /// Result returned by most Java operations that may contain a local reference
 /// to a thrown exception.
@@ -36,6 +44,63 @@ pub enum Error<T: AsJRef<Throwable>> {
     #[error("JVM already exists")]
     JvmAlreadyExists,
 
+    /// The JVM was shut down with [`crate::Jvm::shutdown`], so it can no longer be used.
+    #[error("the JVM has been shut down")]
+    JvmShutDown,
+
+    /// An option given to [`crate::Jvm::builder`] (or in `DUCHESS_JVM_OPTS`) is malformed.
+    #[error("invalid JVM option `{option}`: {reason}")]
+    InvalidJvmOption { option: String, reason: String },
+
+    /// `JNI_CreateJavaVM` failed, usually because the JVM did not accept one of its options.
+    ///
+    /// duchess checks the options built by the typed [`JvmBuilder`](`crate::jvm::JvmBuilder`) methods, but passes
+    /// those given to `custom` or in `DUCHESS_JVM_OPTS` on as-is; these are listed in `unchecked_options`,
+    /// and one of them is most likely to blame. `options` are all the options the JVM was given.
+    #[error(
+        "failed to create the JVM (JNI error code `{code}`), {}",
+        describe_unchecked_options(.unchecked_options, .options)
+    )]
+    JvmCreationFailed {
+        code: i32,
+        options: Vec<String>,
+        unchecked_options: Vec<String>,
+    },
+
+    /// No public member of a class matches a [`DynClass`](`crate::DynClass`) or [`DynObject`](`crate::DynObject`) call.
+    #[error("no public member of `{class}` matches `{member}`: {reason}")]
+    NoMatchingMember {
+        class: String,
+        member: String,
+        reason: String,
+    },
+
+    /// A [`DynValue`](`crate::DynValue`) was converted to a Rust type it doesn't hold.
+    #[error("expected a `{expected}` value, found `{found}`")]
+    DynTypeMismatch {
+        expected: &'static str,
+        found: String,
+    },
+
+    /// A `char` has no equivalent on the other side: either a Rust `char` outside the
+    /// Basic Multilingual Plane, or a Java `char` that is half of a surrogate pair.
+    #[error("`U+{0:04X}` cannot be converted between a Rust `char` and a Java `char`")]
+    CharOutOfRange(u32),
+
+    /// A date, time or duration is outside the range that the other side can represent.
+    #[error("`{value}` is out of range for `{target}`")]
+    TimeOutOfRange { value: String, target: &'static str },
+
+    /// The memory of a `java.nio.ByteBuffer` cannot be viewed from Rust, because the buffer is
+    /// not direct (`"non-direct"`) or a mutable view was requested of a `"read-only"` buffer.
+    #[error("cannot view the memory of a {0} `ByteBuffer`")]
+    UnviewableByteBuffer(&'static str),
+
+    /// A value could not be converted by [`duchess::serde`](`crate::serde`).
+    #[cfg(feature = "serde")]
+    #[error("{0}")]
+    Serde(String),
+
     #[cfg(feature = "dylibjvm")]
     #[error(transparent)]
     UnableToLoadLibjvm(#[from] Box<dyn std::error::Error + Send + Sync + 'static>),
@@ -44,6 +109,16 @@ pub enum Error<T: AsJRef<Throwable>> {
     JvmInternal(String),
 }
 
+fn describe_unchecked_options(unchecked: &[String], all: &[String]) -> String {
+    match unchecked {
+        [] => format!("check that its options are valid: {all:?}"),
+        [option] => {
+            format!("check the option `{option}`, the only one that duchess did not validate")
+        }
+        _ => format!("check the options that duchess did not validate: {unchecked:?}"),
+    }
+}
+
 fn try_extract_message(exception: &impl AsJRef<Throwable>) -> String {
     let result =
         || -> crate::Result<_> { exception.as_jref()?.to_string().assert_not_null().execute() };
@@ -62,14 +137,154 @@ where
 impl<'jvm> Error<Local<'jvm, Throwable>> {
     pub fn into_global(self, jvm: &mut Jvm<'jvm>) -> Error<Java<Throwable>> {
         match self {
-            Error::Thrown(t) => Error::Thrown(jvm.global(&t)),
-            Error::SliceTooLong(s) => Error::SliceTooLong(s),
+            // Moved rather than copied, so the original error stays available as the source
+            #[cfg(feature = "dylibjvm")]
+            Error::UnableToLoadLibjvm(e) => Error::UnableToLoadLibjvm(e),
+            _ => self.map_thrown(|t| Error::Thrown(jvm.global::<Throwable>(t))),
+        }
+    }
+}
+
+impl<T> Error<T>
+where
+    T: AsJRef<Throwable>,
+{
+    /// Copies this error into the current frame, creating a new local reference to any thrown exception.
+    pub(crate) fn to_local<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> Error<Local<'jvm, Throwable>> {
+        self.map_thrown(|t| match t.as_jref() {
+            Ok(t) => Error::Thrown(jvm.local(t)),
+            Err(NullJRef) => Error::NullDeref,
+        })
+    }
+
+    /// Copies this error, converting a thrown exception with `thrown`; every other variant is cloned.
+    fn map_thrown<U>(&self, thrown: impl FnOnce(&T) -> Error<U>) -> Error<U>
+    where
+        U: AsJRef<Throwable>,
+    {
+        match self {
+            Error::Thrown(t) => thrown(t),
+            Error::SliceTooLong(s) => Error::SliceTooLong(*s),
             Error::NullDeref => Error::NullDeref,
             Error::NestedUsage => Error::NestedUsage,
             Error::JvmAlreadyExists => Error::JvmAlreadyExists,
+            Error::JvmShutDown => Error::JvmShutDown,
+            Error::InvalidJvmOption { option, reason } => Error::InvalidJvmOption {
+                option: option.clone(),
+                reason: reason.clone(),
+            },
+            Error::JvmCreationFailed {
+                code,
+                options,
+                unchecked_options,
+            } => Error::JvmCreationFailed {
+                code: *code,
+                options: options.clone(),
+                unchecked_options: unchecked_options.clone(),
+            },
+            Error::NoMatchingMember {
+                class,
+                member,
+                reason,
+            } => Error::NoMatchingMember {
+                class: class.clone(),
+                member: member.clone(),
+                reason: reason.clone(),
+            },
+            Error::DynTypeMismatch { expected, found } => Error::DynTypeMismatch {
+                expected,
+                found: found.clone(),
+            },
+            Error::CharOutOfRange(c) => Error::CharOutOfRange(*c),
+            Error::TimeOutOfRange { value, target } => Error::TimeOutOfRange {
+                value: value.clone(),
+                target,
+            },
+            Error::UnviewableByteBuffer(r) => Error::UnviewableByteBuffer(r),
+            #[cfg(feature = "serde")]
+            Error::Serde(m) => Error::Serde(m.clone()),
             #[cfg(feature = "dylibjvm")]
-            Error::UnableToLoadLibjvm(e) => Error::UnableToLoadLibjvm(e),
-            Error::JvmInternal(m) => Error::JvmInternal(m),
+            Error::UnableToLoadLibjvm(e) => {
+                Error::UnableToLoadLibjvm(format!("UnableToLoadLibjvm({e:?})").as_str().into())
+            }
+            Error::JvmInternal(m) => Error::JvmInternal(m.clone()),
         }
     }
 }
+
+/// Converts an arbitrary Rust error into a duchess error that can be raised in Java.
+///
+/// A duchess [`Error`] is converted as usual, so a Java exception that travelled through Rust is rethrown
+/// as the original object. Any other error is converted with [`rust_error_to_throwable`].
+pub(crate) fn rust_error_to_java<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    error: &(dyn std::error::Error + 'static),
+) -> Error<Local<'jvm, Throwable>> {
+    if let Some(error) = error.downcast_ref::<Error<Java<Throwable>>>() {
+        return error.to_local(jvm);
+    }
+
+    match rust_error_to_throwable(jvm, error) {
+        Ok(throwable) => Error::Thrown(throwable),
+        Err(e) => e,
+    }
+}
+
+/// Creates a Java exception mirroring `error` and the chain of errors returned by
+/// [`std::error::Error::source`]. Each Rust error becomes a `java.lang.RuntimeException`
+/// carrying its `Display` message, linked to the next one with `Throwable.initCause`.
+///
+/// If a Java exception is found in the chain, it is used as is and the rest of the chain
+/// is whatever Java recorded as its cause.
+pub(crate) fn rust_error_to_throwable<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    error: &(dyn std::error::Error + 'static),
+) -> crate::LocalResult<'jvm, Local<'jvm, Throwable>> {
+    if let Some(Error::Thrown(t)) = error.downcast_ref::<Error<Java<Throwable>>>() {
+        return Ok(jvm.local(t));
+    }
+
+    let throwable = new_runtime_exception(jvm, &error.to_string())?;
+    if let Some(source) = error.source() {
+        let cause = rust_error_to_throwable(jvm, source)?;
+        throwable.init_cause(&cause).do_jni(jvm)?;
+    }
+    Ok(throwable)
+}
+
+/// Invokes the `java.lang.RuntimeException(String)` constructor.
+fn new_runtime_exception<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    message: &str,
+) -> crate::LocalResult<'jvm, Local<'jvm, Throwable>> {
+    const DESCRIPTOR: &CStr = c"(Ljava/lang/String;)V";
+    static CONSTRUCTOR: OnceCell<MethodPtr> = OnceCell::new();
+
+    let class = RuntimeException::class(jvm)?;
+    let constructor = CONSTRUCTOR.get_or_try_init(|| find_constructor(jvm, &class, DESCRIPTOR))?;
+    let message = message.do_jni(jvm)?;
+
+    let env = jvm.env();
+    let exception: Option<Local<RuntimeException>> = unsafe {
+        env.invoke(
+            |env| env.NewObjectA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_raw().as_ptr(),
+                    constructor.as_ptr(),
+                    [jni_sys::jvalue {
+                        l: message.as_raw().as_ptr(),
+                    }]
+                    .as_ptr(),
+                )
+            },
+        )
+    }?;
+    match exception {
+        Some(exception) => Ok(exception.upcast()),
+        None => Err(Error::JvmInternal(
+            "failed to create new `java.lang.RuntimeException`".into(),
+        )),
+    }
+}
@@ -1,7 +1,7 @@
 use std::ffi::CStr;
 
 use crate::{
-    java,
+    class_loader, embedded_class, java,
     jvm::JavaObjectExt,
     raw::{FieldPtr, MethodPtr},
     Jvm, Local, LocalResult,
@@ -11,11 +11,26 @@ pub fn find_class<'jvm>(
     jvm: &mut Jvm<'jvm>,
     jni_name: &CStr,
 ) -> LocalResult<'jvm, Local<'jvm, java::lang::Class>> {
-    let class: Option<Local<java::lang::Class>> = unsafe {
+    let class: Option<Local<java::lang::Class>> = match unsafe {
         // SAFETY: jni_name is a valid pointer to a nul-terminated byte string
         jvm.env()
             .invoke(|env| env.FindClass, |env, f| f(env, jni_name.as_ptr()))
-    }?;
+    } {
+        // `FindClass` only sees the class loader of the current native method (or the system class loader),
+        // so try classes embedded in the binary and the configured class loader before reporting the
+        // original `NoClassDefFoundError`.
+        Err(crate::Error::Thrown(exception)) => {
+            if let Some(class) = embedded_class::define_embedded_class(jvm, jni_name)? {
+                Some(class)
+            } else {
+                match class_loader::load_class(jvm, jni_name) {
+                    Ok(Some(class)) => Some(class),
+                    Ok(None) | Err(_) => return Err(crate::Error::Thrown(exception)),
+                }
+            }
+        }
+        result => result?,
+    };
     class.ok_or_else(|| {
         // Class not existing should've triggered NoClassDefFoundError so something strange is now happening
         crate::Error::JvmInternal(format!(
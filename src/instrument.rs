@@ -0,0 +1,126 @@
+//! Tracing spans and counters for calls between Rust and Java, enabled by the `instrument` feature.
+//!
+//! The span functions are called by generated code, which can't check duchess's features,
+//! so they always exist and do nothing when the feature is disabled.
+
+use std::ffi::CStr;
+#[cfg(feature = "instrument")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Keeps the span of a call between Rust and Java entered until it is dropped.
+#[must_use]
+pub struct CallSpan {
+    #[cfg(feature = "instrument")]
+    _span: tracing::span::EnteredSpan,
+}
+
+/// Enters a span for a call from Rust to a Java method, constructor (named `<init>`) or static field.
+/// `kind` is one of `"method"`, `"constructor"` or `"field"`.
+#[inline]
+pub fn java_call(
+    kind: &'static str,
+    class: &'static CStr,
+    member: &'static CStr,
+    descriptor: &'static CStr,
+) -> CallSpan {
+    #[cfg(feature = "instrument")]
+    {
+        let span = tracing::debug_span!(
+            target: "duchess::jni",
+            "java_call",
+            kind,
+            class = class.to_str().unwrap_or_default(),
+            member = member.to_str().unwrap_or_default(),
+            descriptor = descriptor.to_str().unwrap_or_default(),
+        );
+        CallSpan {
+            _span: span.entered(),
+        }
+    }
+
+    #[cfg(not(feature = "instrument"))]
+    {
+        let _ = (kind, class, member, descriptor);
+        CallSpan {}
+    }
+}
+
+/// Enters a span for a call from Java to a native method implemented in Rust with [`java_function`](`crate::java_function`).
+#[inline]
+pub fn native_callback(
+    class: &'static str,
+    method: &'static str,
+    descriptor: &'static str,
+) -> CallSpan {
+    #[cfg(feature = "instrument")]
+    {
+        let span = tracing::debug_span!(
+            target: "duchess::jni",
+            "native_callback",
+            class,
+            method,
+            descriptor,
+        );
+        CallSpan {
+            _span: span.entered(),
+        }
+    }
+
+    #[cfg(not(feature = "instrument"))]
+    {
+        let _ = (class, method, descriptor);
+        CallSpan {}
+    }
+}
+
+#[cfg(feature = "instrument")]
+pub(crate) struct Counter(AtomicU64);
+
+#[cfg(feature = "instrument")]
+impl Counter {
+    const fn new() -> Self {
+        Counter(AtomicU64::new(0))
+    }
+
+    pub(crate) fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(feature = "instrument")]
+pub(crate) static ATTACHES: Counter = Counter::new();
+#[cfg(feature = "instrument")]
+pub(crate) static GLOBAL_REFS: Counter = Counter::new();
+#[cfg(feature = "instrument")]
+pub(crate) static LOCAL_REFS: Counter = Counter::new();
+#[cfg(feature = "instrument")]
+pub(crate) static EXCEPTIONS: Counter = Counter::new();
+
+/// Counts of JNI events since the program started, see [`Jvm::metrics`](`crate::Jvm::metrics`).
+#[cfg(feature = "instrument")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Metrics {
+    /// Threads attached to the JVM by duchess.
+    pub attaches: u64,
+    /// Global references created, e.g. by [`Jvm::global`](`crate::Jvm::global`) or executing an operation into a [`Java`](`crate::Java`).
+    pub global_refs: u64,
+    /// Local references created, which includes every Java object returned to Rust.
+    pub local_refs: u64,
+    /// Java exceptions thrown into Rust.
+    pub exceptions: u64,
+}
+
+#[cfg(feature = "instrument")]
+pub(crate) fn metrics() -> Metrics {
+    Metrics {
+        attaches: ATTACHES.get(),
+        global_refs: GLOBAL_REFS.get(),
+        local_refs: LOCAL_REFS.get(),
+        exceptions: EXCEPTIONS.get(),
+    }
+}
@@ -22,6 +22,8 @@ macro_rules! identity_rust_op {
     }
 }
 
+pub(crate) use identity_rust_op;
+
 identity_rust_op! {
     (),
     bool,
@@ -30,6 +32,9 @@ identity_rust_op! {
     i16,
     i32,
     i64,
+    f32,
+    f64,
+    String,
 }
 
 impl<O, E, JO, JE> IntoRust<Result<O, E>> for Result<JO, JE>
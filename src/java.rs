@@ -14,6 +14,9 @@ mod auto {
             public java.lang.String toString();
             public final native void notify();
             public final native void notifyAll();
+            // NB: `wait()` and `wait(long)` are overloads of this method (and the latter is `native`
+            // only on some JDKs), so use `wait(0, 0)` to wait without a timeout.
+            public final void wait(long, int) throws java.lang.InterruptedException;
         }
 
         public class java.lang.Throwable {
@@ -84,6 +87,9 @@ mod auto {
             public java.lang.Class[] getInterfaces();
             public java.lang.Class getComponentType();
             public java.lang.Class arrayType();
+            public java.lang.reflect.Method[] getMethods();
+            public java.lang.reflect.Constructor[] getConstructors();
+            public java.lang.reflect.Field[] getFields();
         }
 
         public final class java.lang.String {
@@ -92,13 +98,64 @@ mod auto {
             public boolean isEmpty();
         }
 
+        public final class java.lang.System {
+            public static native int identityHashCode(java.lang.Object);
+            public static java.lang.String getProperty(java.lang.String);
+        }
+
+        public abstract class java.lang.ClassLoader {
+            public java.lang.Class loadClass(java.lang.String) throws java.lang.ClassNotFoundException;
+            public final java.lang.ClassLoader getParent();
+            public static java.lang.ClassLoader getSystemClassLoader();
+        }
+
+        public class java.lang.Thread {
+            public static native java.lang.Thread currentThread();
+            public final java.lang.String getName();
+            public final boolean isDaemon();
+            public java.lang.ClassLoader getContextClassLoader();
+            public void setContextClassLoader(java.lang.ClassLoader);
+        }
+
         public abstract class java.lang.Record {
             public abstract boolean equals(java.lang.Object);
             public abstract int hashCode();
             public abstract java.lang.String toString();
         }
 
-        public final class java.lang.Long {
+        public abstract class java.lang.Number {
+            public abstract int intValue();
+            public abstract long longValue();
+            public abstract float floatValue();
+            public abstract double doubleValue();
+            public byte byteValue();
+            public short shortValue();
+        }
+
+        public final class java.lang.Boolean {
+            public static java.lang.Boolean valueOf(boolean);
+            public boolean booleanValue();
+        }
+
+        public final class java.lang.Character {
+            public static java.lang.Character valueOf(char);
+            public char charValue();
+        }
+
+        public final class java.lang.Byte extends java.lang.Number {
+            public static java.lang.Byte valueOf(byte);
+        }
+
+        public final class java.lang.Short extends java.lang.Number {
+            public static java.lang.Short valueOf(short);
+        }
+
+        public final class java.lang.Integer extends java.lang.Number {
+            public static java.lang.Integer valueOf(int);
+        }
+
+        // NB: `valueOf(long)` is an overload of `valueOf(String)`, so it is not mirrored.
+        public final class java.lang.Long extends java.lang.Number {
             public static long parseLong(java.lang.String) throws java.lang.NumberFormatException;
             public static long parseUnsignedLong(java.lang.String) throws java.lang.NumberFormatException;
             public static java.lang.Long valueOf(java.lang.String) throws java.lang.NumberFormatException;
@@ -113,9 +170,50 @@ mod auto {
             public static java.lang.Long getLong(java.lang.String);
           }
 
+        public final class java.lang.Float extends java.lang.Number {
+            public static java.lang.Float valueOf(float);
+        }
+
+        public final class java.lang.Double extends java.lang.Number {
+            public static java.lang.Double valueOf(double);
+        }
+
+        package java.lang.reflect;
+
+        public abstract class java.lang.reflect.Executable {
+            public abstract java.lang.String getName();
+            public abstract int getModifiers();
+            public abstract java.lang.Class[] getParameterTypes();
+        }
+
+        public final class java.lang.reflect.Method extends java.lang.reflect.Executable {
+            public java.lang.Class getReturnType();
+            public boolean isBridge();
+        }
+
+        // NB: In Java, this is `Constructor<T>`, but like `Class` we model the erased version,
+        // because `Class.getConstructors()` returns a `Constructor<?>[]`.
+        public final class java.lang.reflect.Constructor extends java.lang.reflect.Executable {
+        }
+
+        public final class java.lang.reflect.Field {
+            public java.lang.String getName();
+            public int getModifiers();
+            public java.lang.Class getType();
+        }
+
         package java.util;
 
-        public interface java.util.List<E> {
+        public interface java.util.Collection<E> {
+            public abstract int size();
+            public abstract boolean isEmpty();
+            public abstract java.lang.Object[] toArray();
+        }
+
+        public interface java.util.Set<E> extends java.util.Collection<E> {
+        }
+
+        public interface java.util.List<E> extends java.util.Collection<E> {
             public abstract int size();
             public abstract boolean isEmpty();
             public abstract boolean contains(java.lang.Object);
@@ -159,6 +257,21 @@ mod auto {
             public java.util.List<E> subList(int, int);
         }
 
+        public class java.util.HashSet<E> implements java.util.Set<E> {
+            public java.util.HashSet();
+            public int size();
+            public boolean isEmpty();
+            public boolean contains(java.lang.Object);
+            public boolean add(E);
+            public boolean remove(java.lang.Object);
+            public void clear();
+            public java.lang.Object[] toArray();
+        }
+
+        public class java.util.LinkedHashSet<E> extends java.util.HashSet<E> implements java.util.Set<E> {
+            public java.util.LinkedHashSet();
+        }
+
         public interface java.util.Map<K, V> {
             public abstract int size();
             public abstract boolean isEmpty();
@@ -169,7 +282,7 @@ mod auto {
             public abstract V remove(java.lang.Object);
             public abstract void putAll(java.util.Map<? extends K, ? extends V>);
             public abstract void clear();
-            // public abstract java.util.Set<K> keySet();
+            public abstract java.util.Set<K> keySet();
             // public abstract java.util.Collection<V> values();
             // public abstract java.util.Set<java.util.Map$Entry<K, V>> entrySet();
             public abstract boolean equals(java.lang.Object);
@@ -218,7 +331,7 @@ mod auto {
             public V remove(java.lang.Object);
             public void clear();
             public boolean containsValue(java.lang.Object);
-            // public java.util.Set<K> keySet();
+            public java.util.Set<K> keySet();
             // public java.util.Collection<V> values();
             // public java.util.Set<java.util.Map$Entry<K, V>> entrySet();
             public V getOrDefault(java.lang.Object, V);
@@ -235,6 +348,47 @@ mod auto {
             public java.lang.Object clone();
         }
 
+        public class java.util.LinkedHashMap<K, V> extends java.util.HashMap<K, V> implements java.util.Map<K, V> {
+            public java.util.LinkedHashMap();
+        }
+
+        public final class java.util.Optional<T> {
+            // public static <T> java.util.Optional<T> empty();
+            // public static <T> java.util.Optional<T> of(T);
+            // public static <T> java.util.Optional<T> ofNullable(T);
+            public T get();
+            public boolean isPresent();
+            public boolean isEmpty();
+            public T orElse(T);
+        }
+
+        public final class java.util.OptionalInt {
+            public static java.util.OptionalInt empty();
+            public static java.util.OptionalInt of(int);
+            public int getAsInt();
+            public boolean isPresent();
+            public boolean isEmpty();
+            public int orElse(int);
+        }
+
+        public final class java.util.OptionalLong {
+            public static java.util.OptionalLong empty();
+            public static java.util.OptionalLong of(long);
+            public long getAsLong();
+            public boolean isPresent();
+            public boolean isEmpty();
+            public long orElse(long);
+        }
+
+        public final class java.util.OptionalDouble {
+            public static java.util.OptionalDouble empty();
+            public static java.util.OptionalDouble of(double);
+            public double getAsDouble();
+            public boolean isPresent();
+            public boolean isEmpty();
+            public double orElse(double);
+        }
+
         public class java.util.Date {
             public java.util.Date();
             //   public java.util.Date(long);
@@ -278,7 +432,7 @@ mod auto {
 
         package java.time;
 
-        public final class java.time.Instant {
+        public final class java.time.Instant implements java.time.temporal.Temporal {
             public static final java.time.Instant EPOCH;
             public static final java.time.Instant MIN;
             public static final java.time.Instant MAX;
@@ -287,7 +441,7 @@ mod auto {
             // public static java.time.Instant ofEpochSecond(long);
             public static java.time.Instant ofEpochSecond(long, long);
             public static java.time.Instant ofEpochMilli(long);
-            // public static java.time.Instant from(java.time.temporal.TemporalAccessor);
+            public static java.time.Instant from(java.time.temporal.TemporalAccessor);
             // public static java.time.Instant parse(java.lang.CharSequence);
             // public boolean isSupported(java.time.temporal.TemporalField);
             // public boolean isSupported(java.time.temporal.TemporalUnit);
@@ -313,7 +467,7 @@ mod auto {
             // public java.time.temporal.Temporal adjustInto(java.time.temporal.Temporal);
             // public long until(java.time.temporal.Temporal, java.time.temporal.TemporalUnit);
             // public java.time.OffsetDateTime atOffset(java.time.ZoneOffset);
-            // public java.time.ZonedDateTime atZone(java.time.ZoneId);
+            public java.time.ZonedDateTime atZone(java.time.ZoneId);
             public long toEpochMilli();
             public int compareTo(java.time.Instant);
             public boolean isAfter(java.time.Instant);
@@ -332,6 +486,180 @@ mod auto {
             // public int compareTo(java.lang.Object);
         }
 
+        public final class java.time.Duration {
+            public static final java.time.Duration ZERO;
+            // public static java.time.Duration ofSeconds(long);
+            public static java.time.Duration ofSeconds(long, long);
+            public static java.time.Duration ofMillis(long);
+            public static java.time.Duration ofNanos(long);
+            public long getSeconds();
+            public int getNano();
+            public boolean isZero();
+            public boolean isNegative();
+            public int compareTo(java.time.Duration);
+            public boolean equals(java.lang.Object);
+            public int hashCode();
+            public java.lang.String toString();
+        }
+
+        public final class java.time.LocalDate implements java.time.temporal.Temporal {
+            public static java.time.LocalDate now();
+            // public static java.time.LocalDate of(int, java.time.Month, int);
+            public static java.time.LocalDate of(int, int, int);
+            public static java.time.LocalDate ofEpochDay(long);
+            public int getYear();
+            public int getMonthValue();
+            public int getDayOfMonth();
+            public long toEpochDay();
+            public java.time.LocalDateTime atStartOfDay();
+            public boolean equals(java.lang.Object);
+            public int hashCode();
+            public java.lang.String toString();
+        }
+
+        public final class java.time.LocalDateTime implements java.time.temporal.Temporal {
+            public static java.time.LocalDateTime now();
+            // public static java.time.LocalDateTime of(int, int, int, int, int);
+            // public static java.time.LocalDateTime of(int, int, int, int, int, int);
+            public static java.time.LocalDateTime of(int, int, int, int, int, int, int);
+            public static java.time.LocalDateTime ofInstant(java.time.Instant, java.time.ZoneId);
+            public int getYear();
+            public int getMonthValue();
+            public int getDayOfMonth();
+            public int getHour();
+            public int getMinute();
+            public int getSecond();
+            public int getNano();
+            public java.time.LocalDate toLocalDate();
+            public java.time.ZonedDateTime atZone(java.time.ZoneId);
+            public boolean equals(java.lang.Object);
+            public int hashCode();
+            public java.lang.String toString();
+        }
+
+        public final class java.time.ZonedDateTime implements java.time.temporal.Temporal {
+            public static java.time.ZonedDateTime now();
+            // public static java.time.ZonedDateTime of(java.time.LocalDateTime, java.time.ZoneId);
+            public static java.time.ZonedDateTime ofInstant(java.time.Instant, java.time.ZoneId);
+            public java.time.ZoneOffset getOffset();
+            public java.time.ZoneId getZone();
+            public java.time.LocalDateTime toLocalDateTime();
+            public java.time.LocalDate toLocalDate();
+            public int getNano();
+            public boolean equals(java.lang.Object);
+            public int hashCode();
+            public java.lang.String toString();
+        }
+
+        public abstract class java.time.ZoneId {
+            public static java.time.ZoneId systemDefault();
+            // public static java.time.ZoneId of(java.lang.String, java.util.Map<java.lang.String, java.lang.String>);
+            public static java.time.ZoneId of(java.lang.String);
+            public abstract java.lang.String getId();
+            public boolean equals(java.lang.Object);
+            public int hashCode();
+            public java.lang.String toString();
+        }
+
+        public final class java.time.ZoneOffset extends java.time.ZoneId implements java.time.temporal.TemporalAccessor {
+            public static final java.time.ZoneOffset UTC;
+            public static java.time.ZoneOffset ofTotalSeconds(int);
+            public int getTotalSeconds();
+            public java.lang.String getId();
+        }
+
+        package java.time.temporal;
+
+        public interface java.time.temporal.TemporalAccessor {
+        }
+
+        public interface java.time.temporal.Temporal extends java.time.temporal.TemporalAccessor {
+        }
+
+        package java.util.function;
+
+        public interface java.util.function.BiConsumer<T, U> {
+            public abstract void accept(T, U);
+        }
+
+        package java.util.concurrent;
+
+        public interface java.util.concurrent.CompletionStage<T> {
+            public abstract java.util.concurrent.CompletionStage<T> whenComplete(java.util.function.BiConsumer<? super T, ? super java.lang.Throwable>);
+            public abstract java.util.concurrent.CompletableFuture<T> toCompletableFuture();
+        }
+
+        public class java.util.concurrent.CompletableFuture<T> implements java.util.concurrent.CompletionStage<T> {
+            public java.util.concurrent.CompletableFuture();
+            public boolean isDone();
+            public T get() throws java.lang.InterruptedException, java.util.concurrent.ExecutionException;
+            public T join();
+            public T getNow(T);
+            public boolean complete(T);
+            public boolean completeExceptionally(java.lang.Throwable);
+            public java.util.concurrent.CompletableFuture<T> whenComplete(java.util.function.BiConsumer<? super T, ? super java.lang.Throwable>);
+            public java.util.concurrent.CompletableFuture<T> toCompletableFuture();
+            public boolean cancel(boolean);
+            public boolean isCancelled();
+            public boolean isCompletedExceptionally();
+            public java.lang.String toString();
+        }
+
+        public class java.util.concurrent.CompletionException extends java.lang.RuntimeException {
+        }
+
+        package java.nio;
+
+        public abstract class java.nio.Buffer {
+            public final int capacity();
+            public final int position();
+            public final int limit();
+            public final int remaining();
+            public final boolean hasRemaining();
+            public abstract boolean isReadOnly();
+            public abstract boolean isDirect();
+        }
+
+        public abstract class java.nio.ByteBuffer extends java.nio.Buffer {
+            public static java.nio.ByteBuffer allocateDirect(int);
+            public static java.nio.ByteBuffer allocate(int);
+            public static java.nio.ByteBuffer wrap(byte[]);
+            public abstract java.nio.ByteBuffer slice();
+            public abstract java.nio.ByteBuffer duplicate();
+            public abstract java.nio.ByteBuffer asReadOnlyBuffer();
+            public abstract byte get(int);
+            public abstract java.nio.ByteBuffer put(int, byte);
+            public final boolean hasArray();
+            public abstract boolean isDirect();
+        }
+
+        package java.io;
+
+        public class java.io.File {
+            public java.io.File(java.lang.String);
+            public java.net.URI toURI();
+        }
+
+        package java.net;
+
+        public final class java.net.URI {
+            public java.net.URL toURL() throws java.net.MalformedURLException;
+        }
+
+        public final class java.net.URL {
+            public java.lang.String toString();
+        }
+
+        public class java.net.URLClassLoader extends java.security.SecureClassLoader {
+            public java.net.URLClassLoader(java.net.URL[]);
+            public void close() throws java.io.IOException;
+        }
+
+        package java.security;
+
+        public class java.security.SecureClassLoader extends java.lang.ClassLoader {
+        }
+
         package java.lang.management;
 
         public interface java.lang.management.MemoryManagerMXBean {
@@ -402,3 +730,8 @@ pub use auto::java::*;
 // Should it go somewhere outside of the JDK core classes?
 pub use crate::array::JavaArray as Array;
 pub use crate::array::JavaArrayExt as ArrayExt;
+pub use crate::array::JavaObjectArrayExt as ObjectArrayExt;
+pub use crate::array::JavaPrimitive as Primitive;
+pub use crate::array::JavaPrimitiveArrayExt as PrimitiveArrayExt;
+pub use crate::byte_buffer::with_direct_byte_buffer;
+pub use crate::byte_buffer::JavaByteBufferExt as ByteBufferExt;
@@ -0,0 +1,104 @@
+use jni::{
+    objects::{GlobalRef, JObject},
+    JNIEnv,
+};
+
+use crate::{
+    java::lang::Object,
+    jvm::JavaObjectExt,
+    raw::{EnvPtr, ObjectPtr},
+    Error, IntoRust, JavaObject, Jvm, JvmOp, Local,
+};
+
+impl<'jvm> Jvm<'jvm> {
+    /// Runs `op` with a duchess `Jvm` for an environment from the [`jni`] crate, such as the one
+    /// given to a native function written with `jni`. References created inside `op` live as long
+    /// as the `env`'s local frame, so they can be converted back with `JObject::from`.
+    ///
+    /// Errors are returned as-is; to rethrow an [`Error::Thrown`] exception to the Java caller,
+    /// convert it into a `JThrowable` and pass it to `env.throw`.
+    pub fn from_jni_env<R>(
+        env: &mut JNIEnv<'jvm>,
+        op: impl FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+    ) -> crate::LocalResult<'jvm, R> {
+        // SAFETY: a `JNIEnv<'jvm>` is only valid while the thread is attached, for all of `'jvm`
+        unsafe {
+            let env = EnvPtr::new(env.get_raw()).expect("non-null JNIEnv");
+            Jvm::with_attached_env(env, op)
+        }
+    }
+
+    /// Runs `op` with a [`jni`] crate environment for this `Jvm`, so that code written against
+    /// `jni` can be called from duchess. Objects that `op` creates are local references that live
+    /// as long as this `Jvm`.
+    ///
+    /// If `op` fails because Java threw an exception, the exception is returned as [`Error::Thrown`].
+    /// Other `jni` errors become [`Error::JvmInternal`].
+    pub fn with_jni_env<R>(
+        &mut self,
+        op: impl FnOnce(&mut JNIEnv<'jvm>) -> jni::errors::Result<R>,
+    ) -> crate::LocalResult<'jvm, R> {
+        let env = self.env();
+        // SAFETY: the env stays attached for `'jvm`, and borrowing `self` keeps duchess from using it meanwhile
+        let mut jni_env = unsafe { JNIEnv::from_raw(env.as_ptr()) }.expect("non-null JNIEnv");
+        match op(&mut jni_env) {
+            Ok(r) => Ok(r),
+            Err(jni::errors::Error::JavaException) => {
+                env.check_exception()?;
+                Err(Error::JvmInternal(
+                    "`jni` reported a Java exception, but none is pending".to_string(),
+                ))
+            }
+            Err(e) => Err(Error::JvmInternal(format!("`jni` call failed: {e}"))),
+        }
+    }
+
+    /// Creates a [`jni`] crate [`GlobalRef`] to `r`, which can be stored by code that uses `jni`.
+    pub fn jni_global_ref<R>(&mut self, r: &R) -> crate::LocalResult<'jvm, GlobalRef>
+    where
+        R: JavaObject,
+    {
+        // SAFETY: `r` is a live reference to a Java object; the `JObject` doesn't own it
+        let obj = unsafe { JObject::from_raw(r.as_raw().as_ptr()) };
+        self.with_jni_env(|env| env.new_global_ref(&obj))
+    }
+}
+
+/// Hands a local reference over to the [`jni`] crate, which leaves it to be freed with the local frame.
+impl<'jvm, T: JavaObject> From<Local<'jvm, T>> for JObject<'jvm> {
+    fn from(local: Local<'jvm, T>) -> Self {
+        // SAFETY: the `JObject` can't escape `'jvm`, which is the frame the reference lives in
+        unsafe { JObject::from_raw(local.into_raw().as_ptr()) }
+    }
+}
+
+/// Executing a `&JObject` creates a new duchess local reference to the same object,
+/// or `None` if it is null. Use [`try_downcast`][`JvmOp::try_downcast`] to get a more specific type.
+impl JvmOp for &JObject<'_> {
+    type Output<'jvm> = Option<Local<'jvm, Object>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let Some(obj) = ObjectPtr::new(self.as_raw()) else {
+            return Ok(None);
+        };
+        // SAFETY: a non-null `JObject` is a live reference on this thread, and every object is an `Object`
+        Ok(Some(jvm.local(unsafe { obj.as_ref::<Object>() })))
+    }
+}
+
+/// Executing a `&GlobalRef` creates a new duchess local reference to the same object,
+/// or `None` if it is null.
+impl JvmOp for &GlobalRef {
+    type Output<'jvm> = Option<Local<'jvm, Object>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        self.as_obj().do_jni(jvm)
+    }
+}
+
+/// A `GlobalRef` is valid outside of any `Jvm`, so operations can return it as-is.
+impl IntoRust<GlobalRef> for GlobalRef {
+    fn into_rust<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, GlobalRef> {
+        Ok(self)
+    }
+}
@@ -1,15 +1,24 @@
+#[cfg(feature = "tokio")]
+use crate::async_pool::{self, AsyncExecution};
 use crate::{
     cast::{AsUpcast, TryDowncast, Upcast},
+    class_loader::{self, ClassResolver, ScopedClassLoader, WithClassLoader},
+    combinator::{AndThen, Inspect, Map},
+    embedded_class::{self, EmbeddedClass},
     find::find_class,
     into_rust::ToRustOp,
     java::lang::{Class, Throwable},
+    lifecycle::{self, ActiveOp, JvmHook},
     link::{IntoJavaFns, JavaFunction},
+    monitor::{MonitorGuard, Synchronized},
     not_null::NotNull,
+    opt::{NullPropagating, Opt},
+    ops::IntoJava,
     raw::{self, EnvPtr, JvmPtr, ObjectPtr},
     semver_unstable::{FromRef, ToJavaImpl, ToJavaScalar},
-    thread,
-    try_catch::TryCatch,
-    AsJRef, Error, IntoRust, Java, Local, Result, ToJava, TryJDeref,
+    thread::{self, AttachPolicy},
+    try_catch::{Catch, Finally, TryCatch},
+    AsJRef, Error, IntoRust, Java, Local, Result, ToJava, TryJDeref, WeakJava,
 };
 
 use std::{
@@ -45,6 +54,29 @@ pub trait JvmOp: Clone {
         NotNull::new(self)
     }
 
+    /// Makes this operation produce `None` instead of failing with [`Error::NullDeref`] when it
+    /// dereferences a null reference, like Kotlin's `?.` operator. Put it at the end of a chain
+    /// of calls, and any null result along the way short-circuits the whole chain:
+    /// ```ignore
+    /// // `None` if there is no manager, or if the manager has no address
+    /// let street: Option<String> = employee.get_manager().get_address().get_street().opt().execute()?;
+    /// ```
+    /// Exceptions thrown by Java are still reported as errors.
+    /// The output is a single `Option`, even if the operation already produced one.
+    ///
+    /// Every [`Error::NullDeref`] raised while running the operation becomes `None`, not just those
+    /// from the receivers of the chain: that includes a chain passed as an argument (so in
+    /// `list.add(widget.get_label()).opt()`, a null `widget` skips the `add` call) and an
+    /// [`assert_not_null`](`JvmOp::assert_not_null`) along the way. A null argument value itself is
+    /// passed to Java as is and does not short-circuit anything. To keep some null dereferences as errors,
+    /// execute that part separately, or put `opt` on the argument's chain instead.
+    fn opt(self) -> Opt<Self>
+    where
+        for<'jvm> Self::Output<'jvm>: NullPropagating,
+    {
+        Opt::new(self)
+    }
+
     /// Tries to downcast output of this operation to `To`, otherwise returning
     /// the output as is. Equivalent to
     /// ```java
@@ -75,13 +107,116 @@ pub trait JvmOp: Clone {
         AsUpcast::new(self)
     }
 
+    /// Catches Java exceptions of type `J` thrown by this operation,
+    /// producing `Ok` with the output or `Err` with the caught exception.
+    /// Other exceptions are rethrown. `J` can also be a tuple of exception types,
+    /// equivalent to
+    /// ```java
+    /// try {
+    ///    return Ok(op());
+    /// } catch (A | B e) {
+    ///    return Err(e);
+    /// }
+    /// ```
+    /// in which case the error is a [`Caught2`][`crate::Caught2`] (resp. `Caught3`, `Caught4`)
+    /// indicating the first type in the tuple that matched.
     fn catch<J>(self) -> TryCatch<Self, J>
     where
-        J: Upcast<Throwable>,
+        J: Catch,
     {
         TryCatch::new(self)
     }
 
+    /// Catches any Java exception thrown by this operation. Equivalent to `catch::<java::lang::Throwable>()`.
+    fn catch_all(self) -> TryCatch<Self, Throwable> {
+        TryCatch::new(self)
+    }
+
+    /// Runs `cleanup` after this operation, whether or not it threw an exception. Equivalent to
+    /// ```java
+    /// try {
+    ///    return op();
+    /// } finally {
+    ///    cleanup();
+    /// }
+    /// ```
+    /// As in Java, if `cleanup` throws, its exception replaces the outcome of this operation.
+    fn finally<C>(self, cleanup: C) -> Finally<Self, C>
+    where
+        C: JvmOp,
+    {
+        Finally::new(self, cleanup)
+    }
+
+    /// Runs this operation while holding the monitor of `lock`. Equivalent to
+    /// ```java
+    /// synchronized (lock) {
+    ///    return op();
+    /// }
+    /// ```
+    fn synchronized<L>(self, lock: L) -> Synchronized<Self, L::JvmOp>
+    where
+        L: IntoJava<crate::java::lang::Object>,
+    {
+        Synchronized::new(self, lock.into_op())
+    }
+
+    /// Runs this operation with `loader` as the class loader for any Java classes that
+    /// JNI `FindClass` cannot find, such as classes loaded by a servlet container or
+    /// OSGi framework rather than the system class loader.
+    ///
+    /// Note that Java classes are looked up once per Rust type and then cached, so this only
+    /// affects classes that have not been used before.
+    fn with_class_loader<L>(self, loader: L) -> WithClassLoader<Self, L::JvmOp>
+    where
+        L: IntoJava<crate::java::lang::ClassLoader>,
+    {
+        WithClassLoader::new(self, loader.into_op())
+    }
+
+    /// Applies `f` to the output of this operation, as part of the same operation.
+    ///
+    /// The closure's result can't borrow from the JVM, so to keep a Java object from the output,
+    /// return a global reference with [`Jvm::global`].
+    /// ```ignore
+    /// let size: i32 = list.size().map(|size, _jvm| size * 2).execute()?;
+    /// ```
+    fn map<F, R>(self, f: F) -> Map<Self, F, R>
+    where
+        F: Clone + for<'jvm> FnOnce(Self::Output<'jvm>, &mut Jvm<'jvm>) -> R,
+    {
+        Map::new(self, f)
+    }
+
+    /// Like [`JvmOp::map`], but `f` can fail, for example because it runs further operations
+    /// on the output with [`JvmOp::do_jni`]. This builds a multi-step interaction that
+    /// is executed with the thread attached only once:
+    /// ```ignore
+    /// let name: String = list
+    ///     .get(0)
+    ///     .assert_not_null()
+    ///     .and_then(|person, jvm| {
+    ///         person.get_name().assert_not_null().execute_with::<String>(jvm)
+    ///     })
+    ///     .execute()?;
+    /// ```
+    fn and_then<F, R>(self, f: F) -> AndThen<Self, F, R>
+    where
+        F: Clone
+            + for<'jvm> FnOnce(Self::Output<'jvm>, &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+    {
+        AndThen::new(self, f)
+    }
+
+    /// Calls `f` with a reference to the output of this operation, for example to log it,
+    /// and then produces the output unchanged.
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        F: Clone + for<'jvm> FnOnce(&Self::Output<'jvm>, &mut Jvm<'jvm>),
+    {
+        Inspect::new(self, f)
+    }
+
     /// Execute on the JVM, starting a JVM instance if necessary.
     ///
     /// Depending on the type parameter `R`,
@@ -105,7 +240,38 @@ pub trait JvmOp: Clone {
         Jvm::with(|jvm| self.execute_with(jvm))
     }
 
-    /// Internal method
+    /// Like [`JvmOp::execute`], but runs the operation on a pool of threads that stay attached to the JVM
+    /// and returns a future of the result, so that async tasks don't block their runtime while it runs.
+    ///
+    /// Dropping the future cancels the operation if it hasn't started yet. The size of the pool
+    /// can be set with [`JvmBuilder::async_pool_size`].
+    #[cfg(feature = "tokio")]
+    fn execute_async<R>(self) -> AsyncExecution<R>
+    where
+        Self: Send + 'static,
+        for<'jvm> Self::Output<'jvm>: IntoRust<R>,
+        R: Send + 'static,
+    {
+        async_pool::spawn(move || self.execute())
+    }
+
+    /// Like [`JvmOp::execute`], but runs on a thread that is already attached, using its `jvm`
+    /// rather than attaching again. Use it to run further operations from inside closures that receive
+    /// a `jvm`, such as those passed to [`JvmOp::and_then`] or [`JvmOp::map`], where calling `execute`
+    /// would fail with [`Error::NestedUsage`]:
+    /// ```ignore
+    /// let names: Vec<String> = people
+    ///     .and_then(|people, jvm| {
+    ///         let first: String = people.get(0).assert_not_null().get_name().execute_with(jvm)?;
+    ///         let last: String = people.get(1).assert_not_null().get_name().execute_with(jvm)?;
+    ///         Ok(vec![first, last])
+    ///     })
+    ///     .execute()?;
+    /// ```
+    ///
+    /// Any Java exception is returned as an [`Error::Thrown`] holding a [`Local`] reference, which
+    /// is only valid until the closure returns; propagating it with `?` as above turns it into a
+    /// global reference at the end of the outer `execute`.
     fn execute_with<'jvm, R>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>
     where
         for<'j> Self::Output<'j>: IntoRust<R>,
@@ -324,7 +490,7 @@ where
 
 /// Invoked from inside a JNI native function when it is called by the JVM.
 /// If `GLOBAL_JVM` is not yet set, initializes it to use the provided `jvm`.
-/// Otherwise, does nothing.
+/// The first call also captures the thread's context class loader (see [`ClassResolver`]).
 ///
 /// # Safety condition
 ///
@@ -339,7 +505,10 @@ unsafe fn init_jvm_from_native_function(env: EnvPtr<'_>) -> Jvm<'_> {
     let jvm = env.jvm_ptr().unwrap();
     let global_jvm = GLOBAL_JVM.get_or_init(|| jvm);
     assert_eq!(jvm, *global_jvm, "multiple JVM pointers in active use");
-    Jvm(env)
+
+    let mut jvm = Jvm(env);
+    class_loader::capture_context_class_loader(&mut jvm);
+    jvm
 }
 
 fn rust_panic_to_java_exception(env: EnvPtr<'_>, panic: Box<dyn Any + Send + 'static>) {
@@ -375,11 +544,90 @@ impl<'jvm> Jvm<'jvm> {
         JvmBuilder::new()
     }
 
+    /// Attaches the current thread to the JVM until it exits, rather than only for the duration of each operation.
+    /// The Java thread is named after the Rust thread.
     pub fn attach_thread_permanently() -> crate::Result<()> {
-        thread::attach_permanently(get_or_default_init_jvm()?)?;
+        let _active = ActiveOp::enter()?;
+        thread::attach_permanently(get_or_default_init_jvm()?, false)?;
         Ok(())
     }
 
+    /// Like [`Jvm::attach_thread_permanently`], but attaches the current thread as a Java daemon thread,
+    /// which doesn't keep [`Jvm::shutdown`] waiting for it.
+    pub fn attach_thread_as_daemon() -> crate::Result<()> {
+        let _active = ActiveOp::enter()?;
+        thread::attach_permanently(get_or_default_init_jvm()?, true)?;
+        Ok(())
+    }
+
+    /// Returns how many threads were attached, references created and exceptions thrown so far,
+    /// counted by the `instrument` feature.
+    #[cfg(feature = "instrument")]
+    pub fn metrics() -> crate::Metrics {
+        crate::instrument::metrics()
+    }
+
+    /// Spawns a Rust thread named `name` that is attached to the JVM before `f` runs and stays attached
+    /// until it exits, so it shows up under the same name in Java thread dumps. It is attached as a daemon
+    /// thread if the [`AttachPolicy`] of the spawning thread is [`AttachPolicy::Daemon`].
+    ///
+    /// Like [`std::thread::Builder::spawn`], this fails if the thread cannot be created. If the thread
+    /// cannot be attached, `f` is not run and joining the thread returns the error.
+    pub fn spawn<F, T>(
+        name: impl Into<String>,
+        f: F,
+    ) -> std::io::Result<std::thread::JoinHandle<crate::Result<T>>>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let policy = thread::attach_policy();
+        std::thread::Builder::new()
+            .name(name.into())
+            .spawn(move || {
+                if policy == AttachPolicy::Daemon {
+                    Jvm::attach_thread_as_daemon()?;
+                } else {
+                    Jvm::attach_thread_permanently()?;
+                }
+                Ok(f())
+            })
+    }
+
+    /// Runs `op` on the pool used by [`JvmOp::execute_async`] and returns a future of its result.
+    /// Unlike `execute_async`, this works for operations that borrow Java references,
+    /// as long as the closure owns them: `Jvm::run_async(move || list.size().execute())`.
+    #[cfg(feature = "tokio")]
+    pub fn run_async<R>(op: impl FnOnce() -> crate::Result<R> + Send + 'static) -> AsyncExecution<R>
+    where
+        R: Send + 'static,
+    {
+        async_pool::spawn(op)
+    }
+
+    /// Shuts down the JVM in an orderly way:
+    ///
+    /// 1. New operations on other threads fail with [`Error::JvmShutDown`], and running ones are waited for.
+    /// 2. Hooks registered with [`JvmBuilder::on_jvm_shutdown`] are run on this thread.
+    /// 3. The JVM is destroyed with `DestroyJavaVM`, which waits for all non-daemon Java threads
+    ///    (including threads attached with [`Jvm::attach_thread_permanently`]) to finish.
+    ///
+    /// Afterwards every operation fails with [`Error::JvmShutDown`], as a JVM cannot be started again
+    /// in the same process. Java references that are dropped after shutdown are simply forgotten.
+    ///
+    /// Fails with [`Error::NestedUsage`] if called while using the JVM, e.g. from a native function.
+    pub fn shutdown() -> crate::Result<()> {
+        if thread::in_use() {
+            return Err(Error::NestedUsage);
+        }
+
+        lifecycle::shutdown(|| match GLOBAL_JVM.get() {
+            // SAFETY: all other operations are now refused, so the JVM won't be used again
+            Some(jvm) => unsafe { jvm.destroy() },
+            None => Ok(()),
+        })
+    }
+
     /// Call the callback with access to a `Jvm`.
     /// This cannot be invoked recursively.
     /// It is crate-local because it is only usd from within
@@ -387,6 +635,7 @@ impl<'jvm> Jvm<'jvm> {
     pub(crate) fn with<R>(
         op: impl for<'a> FnOnce(&mut Jvm<'a>) -> crate::LocalResult<'a, R>,
     ) -> crate::Result<R> {
+        let _active = ActiveOp::enter()?;
         let jvm = get_or_default_init_jvm()?;
         // SAFTEY: we won't deinitialize the JVM while the guard is live
         let mut guard = unsafe { thread::attach(jvm)? };
@@ -395,6 +644,23 @@ impl<'jvm> Jvm<'jvm> {
         op(&mut jvm).map_err(|e| e.into_global(&mut jvm))
     }
 
+    /// Runs `op` with a `Jvm` for `env`, an environment that the JVM handed to Rust code
+    /// some other way than through duchess (e.g. to a native function written with the `jni` crate).
+    ///
+    /// # Safety
+    ///
+    /// The current thread must be attached to the JVM through `env` for all of `'jvm`.
+    #[cfg(feature = "jni-interop")]
+    pub(crate) unsafe fn with_attached_env<R>(
+        env: EnvPtr<'jvm>,
+        op: impl FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+    ) -> crate::LocalResult<'jvm, R> {
+        let _active = ActiveOp::enter().map_err(|e| e.to_local(&mut Jvm(env)))?;
+        let mut jvm = init_jvm_from_native_function(env);
+        let _callback_guard = thread::attach_from_jni_callback(env);
+        op(&mut jvm)
+    }
+
     pub fn local<R>(&mut self, r: &R) -> Local<'jvm, R>
     where
         R: JavaObject,
@@ -409,6 +675,45 @@ impl<'jvm> Jvm<'jvm> {
         Java::new(self.0, r)
     }
 
+    /// Creates a weak global reference to `r`, which does not keep the object alive.
+    pub fn weak<R>(&mut self, r: &R) -> WeakJava<R>
+    where
+        R: JavaObject,
+    {
+        WeakJava::new(self.0, r)
+    }
+
+    /// Runs `op` while holding the monitor of `lock`, like a `synchronized (lock) { ... }` block in Java.
+    /// The monitor is released when `op` returns, whether it succeeds, fails, or panics.
+    ///
+    /// While holding the monitor, `op` can use `wait` and `notify` on `lock` to take part in
+    /// Java condition-variable protocols.
+    pub fn synchronized<L, R>(
+        &mut self,
+        lock: &L,
+        op: impl FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+    ) -> crate::LocalResult<'jvm, R>
+    where
+        L: AsJRef<crate::java::lang::Object>,
+    {
+        let _guard = MonitorGuard::enter(self, lock.as_jref()?)?;
+        op(self)
+    }
+
+    /// Runs `op` with `loader` as the class loader for any Java classes that JNI `FindClass`
+    /// cannot find. See [`JvmOp::with_class_loader`].
+    pub fn with_class_loader<L, R>(
+        &mut self,
+        loader: &L,
+        op: impl FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, R>,
+    ) -> crate::LocalResult<'jvm, R>
+    where
+        L: AsJRef<crate::java::lang::ClassLoader>,
+    {
+        let _guard = ScopedClassLoader::enter(loader.as_jref()?);
+        op(self)
+    }
+
     /// Plumbing method that should only be used by generated and internal code.
     #[doc(hidden)]
     pub fn env(&self) -> EnvPtr<'jvm> {
@@ -445,25 +750,102 @@ impl<'jvm> Jvm<'jvm> {
     }
 }
 
+/// A garbage collector that the JVM can use, see [`JvmBuilder::garbage_collector`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GarbageCollector {
+    Serial,
+    Parallel,
+    G1,
+    Z,
+    Shenandoah,
+}
+
+impl GarbageCollector {
+    fn option(self) -> &'static str {
+        match self {
+            GarbageCollector::Serial => "-XX:+UseSerialGC",
+            GarbageCollector::Parallel => "-XX:+UseParallelGC",
+            GarbageCollector::G1 => "-XX:+UseG1GC",
+            GarbageCollector::Z => "-XX:+UseZGC",
+            GarbageCollector::Shenandoah => "-XX:+UseShenandoahGC",
+        }
+    }
+}
+
+/// Environment variable with extra options for JVMs launched by duchess, see [`split_jvm_opts`].
+/// They come after the options set on the [`JvmBuilder`], so they take precedence.
+const JVM_OPTS_VAR: &str = "DUCHESS_JVM_OPTS";
+
+/// Splits the value of `DUCHESS_JVM_OPTS` into options. Options are separated by whitespace, and
+/// any part of an option can be quoted with `"` or `'` to include whitespace, as in
+/// `"-Dpath=C:\Program Files\app"`. Backslashes have no special meaning, so Windows paths need no escaping.
+fn split_jvm_opts(value: &str) -> Result<Vec<String>> {
+    let mut options = vec![];
+    let mut option: Option<String> = None;
+    let mut quote = None;
+    for c in value.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => option.get_or_insert_with(String::new).push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                option.get_or_insert_with(String::new);
+            }
+            None if c.is_whitespace() => options.extend(option.take()),
+            None => option.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if let Some(q) = quote {
+        return Err(Error::InvalidJvmOption {
+            option: option.unwrap_or_default(),
+            reason: format!("unterminated `{q}` in `{JVM_OPTS_VAR}`"),
+        });
+    }
+    options.extend(option);
+    Ok(options)
+}
+
 pub struct JvmBuilder {
     options: Vec<String>,
+    /// The options given to [`JvmBuilder::custom`], which duchess passes on without checking them.
+    custom_options: Vec<String>,
+    invalid_option: Option<(String, String)>,
+    check_jni: bool,
+    ignore_unrecognized: bool,
     #[cfg(feature = "dylibjvm")]
     libjvm_path: Option<std::path::PathBuf>,
     java_functions: Vec<JavaFunction>,
+    class_resolver: ClassResolver,
+    attach_policy: AttachPolicy,
+    #[cfg(feature = "tokio")]
+    async_pool_size: Option<usize>,
+    embedded_classes: Vec<EmbeddedClass>,
+    on_start: Vec<JvmHook>,
+    on_shutdown: Vec<JvmHook>,
 }
 
 impl JvmBuilder {
     fn new() -> Self {
         let mut this = Self {
             options: vec![],
+            custom_options: vec![],
+            invalid_option: None,
+            check_jni: cfg!(debug_assertions),
+            ignore_unrecognized: false,
             #[cfg(feature = "dylibjvm")]
             libjvm_path: None,
             java_functions: vec![],
+            class_resolver: ClassResolver::default(),
+            attach_policy: AttachPolicy::default(),
+            #[cfg(feature = "tokio")]
+            async_pool_size: None,
+            embedded_classes: vec![],
+            on_start: vec![],
+            on_shutdown: vec![],
         };
 
-        if cfg!(debug_assertions) {
-            this = this.custom("-Xcheck:jni");
-        }
         if let Ok(classpath) = std::env::var("CLASSPATH") {
             this = this.add_classpath(classpath);
         }
@@ -472,11 +854,151 @@ impl JvmBuilder {
     }
 
     pub fn add_classpath(self, classpath: impl Display) -> Self {
-        self.custom(format!("-Djava.class.path={classpath}"))
+        self.option(format!("-Djava.class.path={classpath}"))
     }
 
+    /// Passes `opt_string` to the JVM as-is.
     pub fn custom(mut self, opt_string: impl Into<String>) -> Self {
-        self.options.push(opt_string.into());
+        let option = opt_string.into();
+        self.custom_options.push(option.clone());
+        self.option(option)
+    }
+
+    /// Adds an option that a typed method has built and checked.
+    fn option(mut self, option: String) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Records a malformed option, which is reported by [`Self::try_launch`].
+    fn invalid(mut self, option: String, reason: &str) -> Self {
+        self.invalid_option
+            .get_or_insert_with(|| (option, reason.to_string()));
+        self
+    }
+
+    fn memory_size(self, flag: &str, bytes: u64) -> Self {
+        let option = format!("{flag}{bytes}");
+        if bytes == 0 {
+            self.invalid(option, "sizes must be greater than zero")
+        } else {
+            self.option(option)
+        }
+    }
+
+    /// Sets the initial size of the heap in bytes (`-Xms`).
+    pub fn initial_heap_size(self, bytes: u64) -> Self {
+        self.memory_size("-Xms", bytes)
+    }
+
+    /// Sets the maximum size of the heap in bytes (`-Xmx`).
+    pub fn max_heap_size(self, bytes: u64) -> Self {
+        self.memory_size("-Xmx", bytes)
+    }
+
+    /// Sets the stack size of Java threads in bytes (`-Xss`).
+    pub fn thread_stack_size(self, bytes: u64) -> Self {
+        self.memory_size("-Xss", bytes)
+    }
+
+    /// Sets a system property, as read by `System.getProperty` (`-Dkey=value`).
+    pub fn system_property(self, key: impl Display, value: impl Display) -> Self {
+        let key = key.to_string();
+        let option = format!("-D{key}={value}");
+        if key.is_empty() || key.contains('=') {
+            self.invalid(
+                option,
+                "property names must be non-empty and cannot contain `=`",
+            )
+        } else {
+            self.option(option)
+        }
+    }
+
+    /// Sets where the JVM looks for Java modules (`--module-path`).
+    pub fn module_path(self, module_path: impl Display) -> Self {
+        let module_path = module_path.to_string();
+        let option = format!("--module-path={module_path}");
+        if module_path.is_empty() {
+            self.invalid(option, "the module path cannot be empty")
+        } else {
+            self.option(option)
+        }
+    }
+
+    /// Adds `--{flag}={module}/{package}={target}`, checking that each part is well-formed.
+    fn module_package_target(
+        self,
+        flag: &str,
+        module: impl Display,
+        package: impl Display,
+        target: impl Display,
+    ) -> Self {
+        let (module, package, target) =
+            (module.to_string(), package.to_string(), target.to_string());
+        let option = format!("--{flag}={module}/{package}={target}");
+        let malformed = |part: &str| part.is_empty() || part.contains(['/', '=']);
+        if malformed(&module) || malformed(&package) || malformed(&target) {
+            self.invalid(
+                option,
+                "the module, package and target must be non-empty and cannot contain `/` or `=`",
+            )
+        } else {
+            self.option(option)
+        }
+    }
+
+    /// Opens `package` of `module` to the `target` module(s) for deep reflection (`--add-opens`),
+    /// e.g. `add_opens("java.base", "java.lang", "ALL-UNNAMED")`.
+    pub fn add_opens(
+        self,
+        module: impl Display,
+        package: impl Display,
+        target: impl Display,
+    ) -> Self {
+        self.module_package_target("add-opens", module, package, target)
+    }
+
+    /// Exports `package` of `module` to the `target` module(s) (`--add-exports`),
+    /// e.g. `add_exports("java.base", "sun.nio.ch", "ALL-UNNAMED")`.
+    pub fn add_exports(
+        self,
+        module: impl Display,
+        package: impl Display,
+        target: impl Display,
+    ) -> Self {
+        self.module_package_target("add-exports", module, package, target)
+    }
+
+    /// Loads the Java agent in the `jar` file, passing it `options` if given (`-javaagent`).
+    pub fn java_agent(self, jar: impl Display, options: Option<&str>) -> Self {
+        let jar = jar.to_string();
+        let option = match options {
+            Some(options) => format!("-javaagent:{jar}={options}"),
+            None => format!("-javaagent:{jar}"),
+        };
+        if jar.is_empty() {
+            self.invalid(option, "the agent jar cannot be empty")
+        } else {
+            self.option(option)
+        }
+    }
+
+    /// Selects the garbage collector, rather than letting the JVM pick one.
+    pub fn garbage_collector(self, gc: GarbageCollector) -> Self {
+        self.option(gc.option().to_string())
+    }
+
+    /// Enables extra checking of JNI calls (`-Xcheck:jni`), which catches misuse of JNI at some cost
+    /// in performance. On by default in debug builds.
+    pub fn check_jni(mut self, check_jni: bool) -> Self {
+        self.check_jni = check_jni;
+        self
+    }
+
+    /// Makes the JVM ignore options that it does not recognize instead of failing to start. Off by default.
+    pub fn ignore_unrecognized(mut self, ignore_unrecognized: bool) -> Self {
+        self.ignore_unrecognized = ignore_unrecognized;
         self
     }
 
@@ -485,37 +1007,155 @@ impl JvmBuilder {
         self
     }
 
+    /// Runs `hook` right after this builder launches the JVM (or finds one already running).
+    /// This is a good place to warm up caches, e.g. by looking up classes with [`JavaObject::class`].
+    /// If the hook fails, launching fails with its error.
+    pub fn on_jvm_start(
+        mut self,
+        hook: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, ()> + Send + 'static,
+    ) -> Self {
+        self.on_start.push(Box::new(hook));
+        self
+    }
+
+    /// Runs `hook` when the JVM is shut down with [`Jvm::shutdown`], after other threads have
+    /// finished using it but before it is destroyed. Failures are logged and otherwise ignored.
+    pub fn on_jvm_shutdown(
+        mut self,
+        hook: impl for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, ()> + Send + 'static,
+    ) -> Self {
+        self.on_shutdown.push(Box::new(hook));
+        self
+    }
+
+    /// Sets how classes are found when JNI `FindClass` cannot see them. See [`ClassResolver`].
+    pub fn class_resolver(mut self, resolver: ClassResolver) -> Self {
+        self.class_resolver = resolver;
+        self
+    }
+
+    /// Sets how threads are attached to the JVM when they first use it. See [`AttachPolicy`].
+    pub fn attach_policy(mut self, policy: AttachPolicy) -> Self {
+        self.attach_policy = policy;
+        self
+    }
+
+    /// Sets the number of threads that run [`JvmOp::execute_async`] operations,
+    /// which defaults to the available parallelism.
+    #[cfg(feature = "tokio")]
+    pub fn async_pool_size(mut self, size: usize) -> Self {
+        self.async_pool_size = Some(size);
+        self
+    }
+
+    /// Registers classes compiled into the Rust binary, which are defined on first use when
+    /// they cannot be found on the classpath. See [`EmbeddedClass`].
+    ///
+    /// The classes are registered when this builder launches the JVM; to register them without
+    /// launching one, use [`register_embedded_classes`](`crate::register_embedded_classes`).
+    pub fn embed_classes(mut self, classes: &[EmbeddedClass]) -> Self {
+        self.embedded_classes.extend_from_slice(classes);
+        self
+    }
+
     #[cfg(feature = "dylibjvm")]
     pub fn load_libjvm_at(mut self, path: impl AsRef<std::path::Path>) -> Self {
         self.libjvm_path = Some(path.as_ref().into());
         self
     }
 
+    /// Installs the process-wide settings from this builder, unless an earlier builder already did,
+    /// and registers its embedded classes.
+    fn apply_settings(&self) {
+        class_loader::set_class_resolver(self.class_resolver);
+        thread::set_attach_policy(self.attach_policy);
+        #[cfg(feature = "tokio")]
+        async_pool::set_pool_size(self.async_pool_size);
+        embedded_class::register_embedded_classes(&self.embedded_classes);
+    }
+
     /// Launch a new JVM, returning [`Error::JvmAlreadyExists`] if one already exists.
     pub fn try_launch(self) -> Result<()> {
+        if !self.create_jvm()? {
+            return Err(Error::JvmAlreadyExists);
+        }
+
+        self.finish_launch()
+    }
+
+    /// Registers the native functions of the JVM this builder just created and runs its hooks.
+    fn finish_launch(self) -> Result<()> {
+        if !self.java_functions.is_empty() {
+            Jvm::with(|jvm| jvm.register_native_methods(&self.java_functions))?;
+        }
+
+        self.run_hooks()
+    }
+
+    /// Creates the JVM, returning false if duchess had already created one.
+    fn create_jvm(&self) -> Result<bool> {
         #[cfg(feature = "dylibjvm")]
-        if let Some(path) = self.libjvm_path {
-            crate::libjvm::libjvm_or_load_at(&path)?;
+        if let Some(path) = &self.libjvm_path {
+            crate::libjvm::libjvm_or_load_at(path)?;
         }
 
+        self.apply_settings();
+
         let mut already_exists = true;
         GLOBAL_JVM.get_or_try_init(|| {
+            let (options, unchecked_options) = self.jvm_options()?;
             // SAFETY: we're behind the GLOBAL_JVM lock and we won't race with other threads creating or finding an
             // existing JVM.
-            let jvm = unsafe { raw::try_create_jvm(self.options.into_iter()) }?;
+            let jvm = unsafe {
+                raw::try_create_jvm(options, unchecked_options, self.ignore_unrecognized)
+            }?;
             already_exists = false;
             Result::Ok(jvm)
         })?;
 
-        if already_exists {
-            Err(Error::JvmAlreadyExists)
-        } else {
-            if !self.java_functions.is_empty() {
-                Jvm::with(|jvm| jvm.register_native_methods(&self.java_functions))?;
-            }
+        Ok(!already_exists)
+    }
+
+    /// Runs the `on_jvm_start` hooks and registers the `on_jvm_shutdown` hooks.
+    fn run_hooks(self) -> Result<()> {
+        lifecycle::add_shutdown_hooks(self.on_shutdown);
+        for hook in self.on_start {
+            Jvm::with(hook)?;
+        }
+        Ok(())
+    }
 
-            Ok(())
+    /// The options to create the JVM with, including those from `DUCHESS_JVM_OPTS`,
+    /// and the subset of them that duchess passes on without checking.
+    fn jvm_options(&self) -> Result<(Vec<String>, Vec<String>)> {
+        if let Some((option, reason)) = &self.invalid_option {
+            return Err(Error::InvalidJvmOption {
+                option: option.clone(),
+                reason: reason.clone(),
+            });
         }
+
+        let mut options = vec![];
+        if self.check_jni {
+            options.push("-Xcheck:jni".to_string());
+        }
+        options.extend(self.options.iter().cloned());
+        let mut unchecked_options = self.custom_options.clone();
+        if let Ok(env_options) = std::env::var(JVM_OPTS_VAR) {
+            let env_options = split_jvm_opts(&env_options)?;
+            options.extend(env_options.iter().cloned());
+            unchecked_options.extend(env_options);
+        }
+
+        // Options the JVM itself would reject are caught here, so we can say which one was wrong
+        if let Some(option) = options.iter().find(|option| !option.starts_with('-')) {
+            return Err(Error::InvalidJvmOption {
+                option: option.clone(),
+                reason: "JVM options must start with `-`".to_string(),
+            });
+        }
+
+        Ok((options, unchecked_options))
     }
 
     pub fn launch_or_use_existing(self) -> Result<()> {
@@ -526,11 +1166,13 @@ impl JvmBuilder {
         let existing_jvm = unsafe { raw::existing_jvm() }?;
 
         if let Some(jvm) = existing_jvm {
+            self.apply_settings();
             let _ = GLOBAL_JVM.set(jvm);
-            return Ok(());
+            return self.run_hooks();
         }
-        match self.try_launch() {
-            Err(Error::JvmAlreadyExists) => {
+        match self.create_jvm() {
+            Ok(true) => self.finish_launch(),
+            Ok(false) | Err(Error::JvmAlreadyExists) => {
                 // Two cases: (1) another thread successfully invoked try_launch() and we'll now get the pointer out of
                 // GLOBAL_JVM, or (2) the JVM was created by some non-duchess code and we'll now need to look it up with
                 // the existing_jvm() call.
@@ -539,9 +1181,9 @@ impl JvmBuilder {
                     // an existing JVM.
                     Result::Ok(unsafe { raw::existing_jvm() }?.expect("JVM should already exist"))
                 })?;
-                Ok(())
+                self.run_hooks()
             }
-            result => result,
+            Err(err) => Err(err),
         }
     }
 }
@@ -1,39 +1,74 @@
 //! Experiments with Java-Rust interop.
 
 mod array;
+#[cfg(feature = "tokio")]
+mod async_pool;
+mod boxed;
+mod byte_buffer;
 mod cast;
+mod class_loader;
+mod collections;
+mod combinator;
+mod completable_future;
+mod dyn_object;
+mod embedded_class;
+mod eq;
 mod error;
 mod find;
 mod from_ref;
+mod instrument;
 mod into_rust;
+#[cfg(feature = "jni-interop")]
+mod jni_interop;
 mod jvm;
 mod libjvm;
+mod lifecycle;
 mod link;
+mod monitor;
 mod not_null;
 mod null;
 mod ops;
+mod opt;
+mod optional;
 mod raw;
 mod ref_;
 mod refs;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod str;
 mod thread;
+mod time;
 mod to_java;
 mod try_catch;
 
 /// Contains reusable declarations for classes distributed by the JDK under the `java.*` packages.
 pub mod java;
 
+#[cfg(feature = "tokio")]
+pub use async_pool::AsyncExecution;
+pub use class_loader::{ClassResolver, WithClassLoader};
+pub use combinator::{join, AndThen, Inspect, Join, Map};
+pub use completable_future::{to_completable_future, CompleteFuture, JavaFuture};
+pub use dyn_object::{DynClass, DynObject, DynValue};
 pub use duchess_macro::{java_function, java_package, ToJava, ToRust};
+pub use embedded_class::{register_embedded_classes, EmbeddedClass};
+pub use eq::{Identity, Semantic};
 pub use error::{Error, LocalResult, Result};
+#[cfg(feature = "instrument")]
+pub use instrument::Metrics;
 pub use into_rust::IntoRust;
 pub use jvm::JavaObject;
 pub use jvm::JavaType;
+pub use jvm::GarbageCollector;
 pub use jvm::Jvm;
 pub use link::JavaFunction;
+pub use monitor::Synchronized;
 pub use null::Null;
-pub use ref_::{Java, Local};
+pub use opt::Opt;
+pub use ref_::{Java, Local, WeakJava};
 pub use refs::{AsJRef, JDeref, NullJRef, Nullable, TryJDeref};
-pub use try_catch::TryCatch;
+pub use thread::AttachPolicy;
+pub use try_catch::{Catch, Caught2, Caught3, Caught4, Finally, TryCatch};
 
 pub use prelude::*;
 
@@ -58,6 +93,7 @@ pub mod semver_unstable {
     pub use crate::cast::Upcast;
     pub use crate::find::{find_class, find_constructor, find_field, find_method};
     pub use crate::from_ref::FromRef;
+    pub use crate::instrument::{java_call, native_callback, CallSpan};
     pub use crate::jvm::native_function_returning_object;
     pub use crate::jvm::native_function_returning_scalar;
     pub use crate::jvm::native_function_returning_unit;
@@ -67,6 +103,7 @@ pub mod semver_unstable {
     pub use crate::jvm::JvmScalarOp;
     pub use crate::link::JavaFn;
     pub use crate::link::JavaFunction;
+    pub use crate::opt::NullPropagating;
     pub use crate::raw::{EnvPtr, FieldPtr, FromJniValue, IntoJniValue, MethodPtr, ObjectPtr};
     pub use crate::refs::NullJRef;
     pub use crate::to_java::{ToJavaImpl, ToJavaScalar};
@@ -0,0 +1,106 @@
+use std::{
+    sync::{
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+        Condvar, Mutex, PoisonError,
+    },
+    thread::ThreadId,
+};
+
+use crate::{Error, Jvm, Result};
+
+/// Hook run with access to the JVM when it starts or shuts down, see [`JvmBuilder::on_jvm_start`](`crate::jvm::JvmBuilder::on_jvm_start`).
+pub(crate) type JvmHook =
+    Box<dyn for<'jvm> FnOnce(&mut Jvm<'jvm>) -> crate::LocalResult<'jvm, ()> + Send>;
+
+const RUNNING: u8 = 0;
+const SHUTTING_DOWN: u8 = 1;
+/// `DestroyJavaVM` is running: operations are refused, but exiting threads still detach.
+const DESTROYING: u8 = 2;
+const SHUT_DOWN: u8 = 3;
+
+static STATE: AtomicU8 = AtomicU8::new(RUNNING);
+
+/// Number of [`ActiveOp`]s across all threads.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// The thread running [`shutdown`], which may keep using the JVM until it is destroyed.
+static SHUTDOWN_THREAD: Mutex<Option<ThreadId>> = Mutex::new(None);
+
+/// Signalled when the last active operation finishes during shutdown.
+static DRAINED: Condvar = Condvar::new();
+
+static SHUTDOWN_HOOKS: Mutex<Vec<JvmHook>> = Mutex::new(Vec::new());
+
+/// Marks the current thread as using the JVM, so that [`shutdown`] waits for it to finish.
+pub(crate) struct ActiveOp(());
+
+impl ActiveOp {
+    /// Fails with [`Error::JvmShutDown`] once shutdown has begun, except on the thread shutting the JVM down.
+    pub(crate) fn enter() -> Result<Self> {
+        // Increment before checking the state, so that `shutdown` either sees us or we see it
+        ACTIVE.fetch_add(1, Ordering::SeqCst);
+        let op = ActiveOp(());
+
+        match STATE.load(Ordering::SeqCst) {
+            RUNNING => Ok(op),
+            SHUTTING_DOWN if is_shutdown_thread() => Ok(op),
+            _ => Err(Error::JvmShutDown),
+        }
+    }
+}
+
+impl Drop for ActiveOp {
+    fn drop(&mut self) {
+        if ACTIVE.fetch_sub(1, Ordering::SeqCst) == 1 && STATE.load(Ordering::SeqCst) != RUNNING {
+            // Take the lock so the notification can't slip in between `shutdown` checking and waiting
+            let _thread = lock(&SHUTDOWN_THREAD);
+            DRAINED.notify_all();
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn is_shutdown_thread() -> bool {
+    *lock(&SHUTDOWN_THREAD) == Some(std::thread::current().id())
+}
+
+/// True once the JVM has been destroyed by [`shutdown`].
+pub(crate) fn is_shut_down() -> bool {
+    STATE.load(Ordering::SeqCst) == SHUT_DOWN
+}
+
+pub(crate) fn add_shutdown_hooks(hooks: impl IntoIterator<Item = JvmHook>) {
+    lock(&SHUTDOWN_HOOKS).extend(hooks);
+}
+
+/// Stops other threads from starting new operations and waits for their running operations to finish,
+/// then runs the shutdown hooks and finally calls `destroy`.
+pub(crate) fn shutdown(destroy: impl FnOnce() -> Result<()>) -> Result<()> {
+    {
+        let mut thread = lock(&SHUTDOWN_THREAD);
+        if thread.is_some() || STATE.load(Ordering::SeqCst) != RUNNING {
+            return Err(Error::JvmShutDown);
+        }
+        *thread = Some(std::thread::current().id());
+        STATE.store(SHUTTING_DOWN, Ordering::SeqCst);
+
+        let _thread = DRAINED
+            .wait_while(thread, |_| ACTIVE.load(Ordering::SeqCst) != 0)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+
+    let hooks = std::mem::take(&mut *lock(&SHUTDOWN_HOOKS));
+    for hook in hooks {
+        if let Err(err) = Jvm::with(hook) {
+            tracing::warn!(?err, "JVM shutdown hook failed");
+        }
+    }
+
+    STATE.store(DESTROYING, Ordering::SeqCst);
+    let result = destroy();
+    STATE.store(SHUT_DOWN, Ordering::SeqCst);
+    result
+}
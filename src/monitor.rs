@@ -0,0 +1,78 @@
+use crate::{
+    java::lang::Object,
+    jvm::{JavaObjectExt, JvmRefOp},
+    raw::{EnvPtr, ObjectPtr},
+    Error, Jvm, JvmOp,
+};
+
+/// Holds the monitor of a Java object, as acquired by `MonitorEnter`, and releases it when dropped
+/// (including when unwinding from a panic).
+pub(crate) struct MonitorGuard<'jvm> {
+    env: EnvPtr<'jvm>,
+    obj: ObjectPtr,
+}
+
+impl<'jvm> MonitorGuard<'jvm> {
+    /// Blocks until the current thread owns the monitor of `obj`, like entering a `synchronized (obj)` block.
+    pub(crate) fn enter(jvm: &mut Jvm<'jvm>, obj: &Object) -> crate::LocalResult<'jvm, Self> {
+        let env = jvm.env();
+        let obj = obj.as_raw();
+
+        // SAFETY: obj is a live reference to a Java object
+        let result =
+            unsafe { env.invoke_unchecked(|env| env.MonitorEnter, |env, f| f(env, obj.as_ptr())) };
+        if result != jni_sys::JNI_OK {
+            env.check_exception()?;
+            return Err(Error::JvmInternal(format!(
+                "MonitorEnter failed with code `{result}`"
+            )));
+        }
+
+        Ok(Self { env, obj })
+    }
+}
+
+impl Drop for MonitorGuard<'_> {
+    fn drop(&mut self) {
+        // SAFETY: we entered the monitor of obj in `enter` and the borrow of obj outlives the guard.
+        // MonitorExit is safe to call while an exception is pending.
+        unsafe {
+            self.env
+                .invoke_unchecked(|env| env.MonitorExit, |env, f| f(env, self.obj.as_ptr()));
+        }
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Lock: Copy)]
+pub struct Synchronized<This, Lock>
+where
+    This: JvmOp,
+    Lock: JvmRefOp<Object>,
+{
+    this: This,
+    lock: Lock,
+}
+
+impl<This, Lock> Synchronized<This, Lock>
+where
+    This: JvmOp,
+    Lock: JvmRefOp<Object>,
+{
+    pub(crate) fn new(this: This, lock: Lock) -> Self {
+        Self { this, lock }
+    }
+}
+
+impl<This, Lock> JvmOp for Synchronized<This, Lock>
+where
+    This: JvmOp,
+    Lock: JvmRefOp<Object>,
+{
+    type Output<'jvm> = This::Output<'jvm>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let lock = self.lock.into_as_jref(jvm)?;
+        jvm.synchronized(&lock, |jvm| self.this.do_jni(jvm))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{jvm::JavaScalar, Error, Java, JavaObject, Jvm, JvmOp, Local};
+
+/// Output of an operation that can be made optional by [`JvmOp::opt`].
+///
+/// Outputs that are already optional, like the possibly null `Option<Local<'jvm, T>>`
+/// returned by Java methods, are kept as is rather than nested in another `Option`.
+pub trait NullPropagating {
+    type Optional;
+
+    fn some(self) -> Self::Optional;
+
+    fn none() -> Self::Optional;
+}
+
+impl<T> NullPropagating for Option<T> {
+    type Optional = Option<T>;
+
+    fn some(self) -> Self::Optional {
+        self
+    }
+
+    fn none() -> Self::Optional {
+        None
+    }
+}
+
+impl<'jvm, T: JavaObject> NullPropagating for Local<'jvm, T> {
+    type Optional = Option<Local<'jvm, T>>;
+
+    fn some(self) -> Self::Optional {
+        Some(self)
+    }
+
+    fn none() -> Self::Optional {
+        None
+    }
+}
+
+impl<T: JavaObject> NullPropagating for Java<T> {
+    type Optional = Option<Java<T>>;
+
+    fn some(self) -> Self::Optional {
+        Some(self)
+    }
+
+    fn none() -> Self::Optional {
+        None
+    }
+}
+
+impl<S: JavaScalar> NullPropagating for S {
+    type Optional = Option<S>;
+
+    fn some(self) -> Self::Optional {
+        Some(self)
+    }
+
+    fn none() -> Self::Optional {
+        None
+    }
+}
+
+impl NullPropagating for () {
+    type Optional = Option<()>;
+
+    fn some(self) -> Self::Optional {
+        Some(self)
+    }
+
+    fn none() -> Self::Optional {
+        None
+    }
+}
+
+/// Produces `None` when an operation dereferences a null reference, see [`JvmOp::opt`].
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy)]
+pub struct Opt<This>
+where
+    This: JvmOp,
+{
+    this: This,
+}
+
+impl<This> Opt<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: NullPropagating,
+{
+    pub(crate) fn new(this: This) -> Self {
+        Self { this }
+    }
+}
+
+impl<This> JvmOp for Opt<This>
+where
+    This: JvmOp,
+    for<'jvm> This::Output<'jvm>: NullPropagating,
+{
+    type Output<'jvm> = <This::Output<'jvm> as NullPropagating>::Optional;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        match self.this.do_jni(jvm) {
+            Ok(output) => Ok(output.some()),
+            Err(Error::NullDeref) => Ok(<This::Output<'jvm> as NullPropagating>::none()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::ffi::CStr;
+
+use once_cell::sync::OnceCell;
+
+use crate::{
+    find::{find_class, find_method},
+    java::{
+        self,
+        util::{Optional, OptionalDouble, OptionalInt, OptionalLong},
+    },
+    jvm::JavaObjectExt,
+    raw::{IntoJniValue, MethodPtr},
+    semver_unstable::ToJavaImpl,
+    Error, IntoRust, Java, JavaObject, Jvm, JvmOp, Local,
+};
+
+/// Wraps `value` with `Optional.ofNullable(T)`, which is not mirrored because it is a static generic method.
+/// A `None` produces `Optional.empty()`.
+fn of_nullable<'jvm, J: JavaObject>(
+    jvm: &mut Jvm<'jvm>,
+    value: Option<&J>,
+) -> crate::LocalResult<'jvm, Option<Local<'jvm, Optional<J>>>> {
+    const CLASS: &CStr = c"java/util/Optional";
+    const NAME: &CStr = c"ofNullable";
+    const DESCRIPTOR: &CStr = c"(Ljava/lang/Object;)Ljava/util/Optional;";
+    static OF_NULLABLE: OnceCell<(Java<java::lang::Class>, MethodPtr)> = OnceCell::new();
+
+    let (class, method) = OF_NULLABLE.get_or_try_init(|| {
+        let class = find_class(jvm, CLASS)?;
+        let method = find_method(jvm, &class, NAME, DESCRIPTOR, true)?;
+        Ok::<_, Error<_>>((jvm.global(&*class), method))
+    })?;
+    let class = class.as_raw();
+    // SAFETY: `method` is the static `Optional.ofNullable(Object)`
+    unsafe {
+        jvm.env().invoke(
+            |env| env.CallStaticObjectMethodA,
+            |env, f| {
+                f(
+                    env,
+                    class.as_ptr(),
+                    method.as_ptr(),
+                    [value.into_jni_value()].as_ptr(),
+                )
+            },
+        )
+    }
+}
+
+/// An empty `Optional` becomes `None`; a present value is converted with `&J: IntoRust<R>`.
+impl<J, R> IntoRust<Option<R>> for &Optional<J>
+where
+    J: JavaObject,
+    for<'a> &'a J: IntoRust<R>,
+{
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Option<R>> {
+        if !self.is_present().do_jni(jvm)? {
+            return Ok(None);
+        }
+        let value = self.get().assert_not_null().do_jni(jvm)?;
+        Ok(Some(value.into_rust(jvm)?))
+    }
+}
+
+/// Implements [`ToJavaImpl`] from a Rust `Option` into `java.util.Optional`, converting a `Some` value to `$java`.
+///
+/// Unlike the blanket impl for `Option<R>`, which maps `None` to `null`, these always produce an
+/// `Optional` object. They are listed per element type because a blanket impl would overlap with it.
+macro_rules! option_to_optional {
+    ($($rust:ty => $java:ty,)*) => {
+        $(
+            impl ToJavaImpl<Optional<$java>> for Option<$rust> {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, Optional<$java>>>> {
+                    let value = match rust {
+                        Some(r) => <$rust as ToJavaImpl<$java>>::to_java_impl(r, jvm)?,
+                        None => None,
+                    };
+                    of_nullable(jvm, value.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+option_to_optional! {
+    bool => java::lang::Boolean,
+    u16 => java::lang::Character,
+    char => java::lang::Character,
+    i8 => java::lang::Byte,
+    i16 => java::lang::Short,
+    i32 => java::lang::Integer,
+    i64 => java::lang::Long,
+    f32 => java::lang::Float,
+    f64 => java::lang::Double,
+    String => java::lang::String,
+    &str => java::lang::String,
+}
+
+impl<J: JavaObject> ToJavaImpl<Optional<J>> for Option<Java<J>> {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, Optional<J>>>> {
+        of_nullable(jvm, rust.as_deref())
+    }
+}
+
+impl<J: JavaObject> ToJavaImpl<Optional<J>> for Option<Local<'_, J>> {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, Optional<J>>>> {
+        of_nullable(jvm, rust.as_deref())
+    }
+}
+
+/// Implements [`IntoRust`] and [`ToJavaImpl`] between a primitive Java optional and `Option` of its scalar.
+macro_rules! primitive_optional {
+    ($($rust:ty: $java:ident $get:ident,)*) => {
+        $(
+            impl IntoRust<Option<$rust>> for &$java {
+                fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Option<$rust>> {
+                    if !self.is_present().do_jni(jvm)? {
+                        return Ok(None);
+                    }
+                    Ok(Some(self.$get().do_jni(jvm)?))
+                }
+            }
+
+            impl ToJavaImpl<$java> for Option<$rust> {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, $java>>> {
+                    match *rust {
+                        Some(value) => $java::of(value).do_jni(jvm),
+                        None => $java::empty().do_jni(jvm),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+primitive_optional! {
+    i32: OptionalInt get_as_int,
+    i64: OptionalLong get_as_long,
+    f64: OptionalDouble get_as_double,
+}
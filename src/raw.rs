@@ -77,24 +77,32 @@ pub(crate) unsafe fn existing_jvm() -> crate::Result<Option<JvmPtr>> {
 }
 
 /// Try to initialize a new JVM with the provided `options`, returning a [`JvmPtr`] on success or an
-/// [`Error::JvmAlreadyExists`] if one already exists.
+/// [`Error::JvmAlreadyExists`] if one already exists. `unchecked_options` are the ones that the JVM
+/// is blamed on if it rejects its options.
 ///
 /// If the `dynlibjvm` feature is enabled and `libjvm` isn't already loaded, it will first force it to be loaded.
 ///
 /// # Safety
 ///
 /// Caller must ensure that no two threads race to call this fn or [`jvm()`].
-pub(crate) unsafe fn try_create_jvm<'a>(
-    options: impl IntoIterator<Item = String>,
+pub(crate) unsafe fn try_create_jvm(
+    options: Vec<String>,
+    unchecked_options: Vec<String>,
+    ignore_unrecognized: bool,
 ) -> crate::Result<JvmPtr> {
     let libjvm = crate::libjvm::libjvm_or_load()?;
 
-    let options = options
-        .into_iter()
-        .map(|opt| ffi::CString::new(opt).unwrap())
-        .collect::<Vec<_>>();
+    let c_options = options
+        .iter()
+        .map(|opt| {
+            ffi::CString::new(opt.as_str()).map_err(|_| Error::InvalidJvmOption {
+                option: opt.clone(),
+                reason: "options cannot contain nul bytes".into(),
+            })
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
 
-    let mut option_ptrs = options
+    let mut option_ptrs = c_options
         .iter()
         .map(|opt| jni_sys::JavaVMOption {
             optionString: opt.as_ptr().cast_mut(),
@@ -104,9 +112,13 @@ pub(crate) unsafe fn try_create_jvm<'a>(
 
     let mut args = jni_sys::JavaVMInitArgs {
         version: VERSION,
-        nOptions: options.len().try_into().unwrap(),
+        nOptions: option_ptrs.len().try_into().unwrap(),
         options: option_ptrs.as_mut_ptr(),
-        ignoreUnrecognized: jni_sys::JNI_FALSE,
+        ignoreUnrecognized: if ignore_unrecognized {
+            jni_sys::JNI_TRUE
+        } else {
+            jni_sys::JNI_FALSE
+        },
     };
 
     let mut jvm = std::ptr::null_mut::<jni_sys::JavaVM>();
@@ -134,9 +146,11 @@ pub(crate) unsafe fn try_create_jvm<'a>(
             Ok(jvm)
         }
         jni_sys::JNI_EEXIST => Err(Error::JvmAlreadyExists),
-        _ => Err(Error::JvmInternal(format!(
-            "CreateJavaVM failed with code `{code}`"
-        ))),
+        _ => Err(Error::JvmCreationFailed {
+            code,
+            options,
+            unchecked_options,
+        }),
     }
 }
 
@@ -174,30 +188,69 @@ impl JvmPtr {
     /// Attaches the current thread to the JVM and returns an [`EnvPtr`] that can be used to invoke JNI methods.
     /// Multiple calls on the same thread are idempotent.
     ///
+    /// The Java thread is named after the current Rust thread, if it has a name, and is a daemon thread if
+    /// `daemon` is true, so it doesn't keep the JVM from being destroyed.
+    ///
     /// # Safety
     ///
     /// The caller must ensure that the `'jvm` lifetime will not live past when the current thread is detached from the
     /// JVM.
-    pub(crate) unsafe fn attach_thread<'jvm>(self) -> crate::Result<EnvPtr<'jvm>> {
+    pub(crate) unsafe fn attach_thread<'jvm>(self, daemon: bool) -> crate::Result<EnvPtr<'jvm>> {
+        // Thread names containing a nul byte are left unnamed rather than truncated
+        let name = std::thread::current()
+            .name()
+            .and_then(|name| ffi::CString::new(name).ok());
+        let mut args = jni_sys::JavaVMAttachArgs {
+            version: VERSION,
+            name: name
+                .as_ref()
+                .map_or(std::ptr::null_mut(), |name| name.as_ptr().cast_mut()),
+            group: std::ptr::null_mut(),
+        };
+
         let mut env_ptr = std::ptr::null_mut::<ffi::c_void>();
         match fn_table_call(
             self.0,
-            |jvm| jvm.AttachCurrentThread,
+            |jvm| {
+                if daemon {
+                    jvm.AttachCurrentThreadAsDaemon
+                } else {
+                    jvm.AttachCurrentThread
+                }
+            },
             |jvm, f| {
                 f(
                     jvm,
                     &mut env_ptr as *mut _,
-                    std::ptr::null_mut(), /* args */
+                    &mut args as *mut jni_sys::JavaVMAttachArgs as *mut ffi::c_void,
                 )
             },
         ) {
-            jni_sys::JNI_OK => Ok(EnvPtr::new(env_ptr.cast()).unwrap()),
+            jni_sys::JNI_OK => {
+                #[cfg(feature = "instrument")]
+                crate::instrument::ATTACHES.increment();
+                Ok(EnvPtr::new(env_ptr.cast()).unwrap())
+            }
             code => Err(Error::JvmInternal(format!(
                 "AttachCurrentThread failed with code `{code}`"
             ))),
         }
     }
 
+    /// Waits until the current thread is the only non-daemon thread attached to the JVM, then unloads it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the JVM is not used again, through this or any other pointer to it.
+    pub(crate) unsafe fn destroy(self) -> crate::Result<()> {
+        match fn_table_call(self.0, |jvm| jvm.DestroyJavaVM, |jvm, f| f(jvm)) {
+            jni_sys::JNI_OK => Ok(()),
+            code => Err(Error::JvmInternal(format!(
+                "DestroyJavaVM failed with code `{code}`"
+            ))),
+        }
+    }
+
     /// Detaches the current thread from the JVM. Multiple calls on the same thread are idempotent.
     ///
     /// # Safety
@@ -251,6 +304,12 @@ impl<'jvm> EnvPtr<'jvm> {
         })
     }
 
+    /// Returns the raw JNI environment pointer, e.g. to hand it to the `jni` crate.
+    #[cfg(feature = "jni-interop")]
+    pub(crate) fn as_ptr(self) -> *mut jni_sys::JNIEnv {
+        self.ptr.as_ptr()
+    }
+
     /// Invoke a JNI method dispatched through a virtual table lookup. Used by codegen to make most JNI calls and so
     /// must be public.
     ///
@@ -341,6 +400,8 @@ impl<'jvm> EnvPtr<'jvm> {
         let thrown = unsafe { self.invoke_unchecked(|env| env.ExceptionOccurred, |env, f| f(env)) };
         if let Some(thrown) = ObjectPtr::new(thrown) {
             unsafe { self.invoke_unchecked(|env| env.ExceptionClear, |env, f| f(env)) };
+            #[cfg(feature = "instrument")]
+            crate::instrument::EXCEPTIONS.increment();
             // SAFETY: the ptr returned by ExceptionOccurred is already a local ref and must be an instance of Throwable
             Err(Error::Thrown(unsafe { Local::from_raw(self, thrown) }))
         } else {
@@ -1,8 +1,9 @@
 use std::{marker::PhantomData, ops::Deref, ptr::NonNull};
 
 use crate::jvm::JavaObjectExt;
+use crate::lifecycle::ActiveOp;
 use crate::thread;
-use crate::{cast::Upcast, raw::EnvPtr, semver_unstable::ObjectPtr, JavaObject};
+use crate::{cast::Upcast, raw::EnvPtr, semver_unstable::ObjectPtr, JavaObject, Jvm, JvmOp};
 
 /// An owned local reference to a non-null Java object of type `T`. The reference will be freed when
 /// dropped. Cannot be shared across threads or [`Jvm::with`] invocations.
@@ -32,6 +33,8 @@ impl<'jvm, T: JavaObject> Local<'jvm, T> {
     /// `from_raw()`), and will not dereferenced after the returned [`Local`] is dropped.
     #[doc(hidden)]
     pub unsafe fn from_raw(env: EnvPtr<'jvm>, obj: ObjectPtr) -> Self {
+        #[cfg(feature = "instrument")]
+        crate::instrument::LOCAL_REFS.increment();
         Self {
             obj,
             env,
@@ -114,39 +117,54 @@ impl<T: JavaObject> Java<T> {
         unsafe {
             let new_ref =
                 env.invoke_unchecked(|e| e.NewGlobalRef, |e, f| f(e, obj.as_raw().as_ptr()));
+            #[cfg(feature = "instrument")]
+            crate::instrument::GLOBAL_REFS.increment();
             Self::from_raw(NonNull::new(new_ref).unwrap().into())
         }
     }
+
+    /// Creates a [`WeakJava`] reference to the same object, which will not keep it alive.
+    pub fn downgrade(&self) -> crate::Result<WeakJava<T>> {
+        Jvm::with(|jvm| Ok(jvm.weak(&**self)))
+    }
 }
 
 impl<T: JavaObject> Drop for Java<T> {
     fn drop(&mut self) {
-        let jvm = crate::jvm::unwrap_global_jvm();
-
         // SAFETY: Global owns the global ref and it's no longer possible to dereference the object pointer.
-        let delete = |env: EnvPtr<'_>| unsafe {
+        with_any_thread_env("delete global ref", |env| unsafe {
             env.invoke_unchecked(
                 |jni| jni.DeleteGlobalRef,
                 |jni, f| f(jni, self.obj.as_ptr()),
             )
-        };
+        });
+    }
+}
 
-        match unsafe { jvm.env() } {
-            Ok(Some(env)) => delete(env),
-            Ok(None) => {
-                // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created
-                match unsafe { thread::attach(jvm) } {
-                    Ok(mut attached) => delete(attached.env()),
-                    Err(err) => {
-                        tracing::warn!(?err, "unable to attach current thread to delete global ref")
-                    }
+/// Runs `op` with an env for the current thread, attaching it to the JVM if needed.
+/// Used to release global refs, which may be dropped on any thread.
+fn with_any_thread_env(what: &str, op: impl FnOnce(EnvPtr<'_>)) {
+    // Once the JVM is shut down there is nothing left to release
+    let Ok(_active) = ActiveOp::enter() else {
+        return;
+    };
+    let jvm = crate::jvm::unwrap_global_jvm();
+
+    match unsafe { jvm.env() } {
+        Ok(Some(env)) => op(env),
+        Ok(None) => {
+            // SAFETY: jvm is a valid pointer since duchess will not deinitialize a JVM once created
+            match unsafe { thread::attach(jvm) } {
+                Ok(mut attached) => op(attached.env()),
+                Err(err) => {
+                    tracing::warn!(?err, "unable to attach current thread to {what}")
                 }
             }
-            Err(err) => tracing::warn!(
-                ?err,
-                "unable to get JNI interface for local thread to delete global ref"
-            ),
         }
+        Err(err) => tracing::warn!(
+            ?err,
+            "unable to get JNI interface for local thread to {what}"
+        ),
     }
 }
 
@@ -163,6 +181,94 @@ impl<T: JavaObject> Deref for Java<T> {
     }
 }
 
+/// A weak global reference to a Java object of type `T`, created with [`Java::downgrade`] or [`Jvm::weak`].
+///
+/// Unlike [`Java`], a `WeakJava` does not keep the object alive: once the object is
+/// garbage collected, [`upgrade`][`WeakJava::upgrade`] returns `None`.
+/// Executing a `&WeakJava<T>` upgrades it, producing `None` (i.e., null) if the object was collected.
+#[derive_where::derive_where(Debug)]
+pub struct WeakJava<T: JavaObject> {
+    obj: ObjectPtr,
+    _marker: PhantomData<T>,
+}
+
+impl<T: JavaObject> WeakJava<T> {
+    /// Creates a *new* weak global reference to `obj` via a `NewWeakGlobalRef` JNI call.
+    pub(crate) fn new(env: EnvPtr<'_>, obj: &T) -> Self {
+        // SAFETY: The JavaObject trait contract ensures that &T points to a Java object that is an instance of T.
+        unsafe {
+            let new_ref =
+                env.invoke_unchecked(|e| e.NewWeakGlobalRef, |e, f| f(e, obj.as_raw().as_ptr()));
+            Self {
+                obj: NonNull::new(new_ref).unwrap().into(),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Returns a local reference to the object, or `None` if it has been garbage collected.
+    pub fn upgrade<'jvm>(&self, jvm: &mut Jvm<'jvm>) -> Option<Local<'jvm, T>> {
+        let env = jvm.env();
+        // SAFETY: NewLocalRef returns null if the weakly referenced object has been collected
+        let new_ref =
+            unsafe { env.invoke_unchecked(|e| e.NewLocalRef, |e, f| f(e, self.obj.as_ptr())) };
+        // SAFETY: new_ref is a fresh local ref to an instance of T
+        ObjectPtr::new(new_ref).map(|obj| unsafe { Local::from_raw(env, obj) })
+    }
+
+    /// Returns a [`JvmOp`][`crate::JvmOp`] that checks whether the object has been garbage collected,
+    /// using `IsSameObject` to compare the weak reference against null.
+    ///
+    /// Note that even if this produces `false`, the object may be collected before
+    /// a later [`upgrade`][`WeakJava::upgrade`].
+    pub fn is_cleared(&self) -> IsCleared<'_, T> {
+        IsCleared { weak: self }
+    }
+}
+
+impl<T: JavaObject> Drop for WeakJava<T> {
+    fn drop(&mut self) {
+        // SAFETY: WeakJava owns the weak global ref and it's no longer possible to use the object pointer.
+        with_any_thread_env("delete weak global ref", |env| unsafe {
+            env.invoke_unchecked(
+                |jni| jni.DeleteWeakGlobalRef,
+                |jni, f| f(jni, self.obj.as_ptr()),
+            )
+        });
+    }
+}
+
+// SAFETY: The JNI promises weak global refs are shareable across threads
+unsafe impl<T: JavaObject> Send for WeakJava<T> {}
+unsafe impl<T: JavaObject> Sync for WeakJava<T> {}
+
+impl<T: JavaObject> JvmOp for &WeakJava<T> {
+    type Output<'jvm> = Option<Local<'jvm, T>>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        Ok(self.upgrade(jvm))
+    }
+}
+
+#[derive_where::derive_where(Copy, Clone)]
+pub struct IsCleared<'a, T: JavaObject> {
+    weak: &'a WeakJava<T>,
+}
+
+impl<T: JavaObject> JvmOp for IsCleared<'_, T> {
+    type Output<'jvm> = bool;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let cleared = unsafe {
+            jvm.env().invoke_unchecked(
+                |e| e.IsSameObject,
+                |e, f| f(e, self.weak.obj.as_ptr(), std::ptr::null_mut()),
+            )
+        };
+        Ok(cleared == jni_sys::JNI_TRUE)
+    }
+}
+
 impl<'a, R, S> AsRef<S> for Local<'a, R>
 where
     R: Upcast<S>,
@@ -182,6 +288,8 @@ impl<'a, R: JavaObject> Local<'a, R> {
     {
         // SAFETY: From the Upcast trait contract, we know R is also an instance of S
         let upcast = unsafe { Local::<S>::from_raw(self.env, self.obj) };
+        // Ownership of the local ref moves into `upcast`, so don't run our destructor
+        std::mem::forget(self);
         upcast
     }
 }
@@ -205,6 +313,8 @@ impl<R: JavaObject> Java<R> {
     {
         // SAFETY: From the Upcast trait contract, we know R is also an instance of S
         let upcast = unsafe { Java::<S>::from_raw(self.obj) };
+        // Ownership of the global ref moves into `upcast`, so don't run our destructor
+        std::mem::forget(self);
         upcast
     }
 }
@@ -0,0 +1,814 @@
+//! Converts between Rust values and Java object graphs with [`serde`](`::serde`), enabled by the `serde` feature.
+//!
+//! | Rust (serde data model)            | Java                                   |
+//! |------------------------------------|----------------------------------------|
+//! | `bool`, `i8` ... `i64`, `f32`, `f64` | `Boolean`, `Byte` ... `Long`, `Float`, `Double` |
+//! | `u8`, `u16`, `u32`, `u64`          | `Short`, `Integer`, `Long`, `Long` (if it fits) |
+//! | `char`, strings                    | `Character`, `String`                  |
+//! | bytes                              | `byte[]`                               |
+//! | `None`, `()`, unit structs         | `null`                                 |
+//! | sequences and tuples               | `ArrayList` (any `Collection` when deserializing) |
+//! | maps and structs                   | `HashMap` (any `Map` when deserializing) |
+//! | unit enum variants                 | the variant name as a `String`         |
+//! | other enum variants                | a `HashMap` from the variant name to its content |
+
+use std::{fmt::Display, marker::PhantomData};
+
+use ::serde::{
+    de::{self, DeserializeOwned, IntoDeserializer, Visitor},
+    ser::{self, Serialize},
+};
+
+use crate::{
+    boxed::long_value_of,
+    java::{
+        self,
+        lang::{Object, Throwable},
+        util::{ArrayList, HashMap},
+        ArrayExt, ObjectArrayExt,
+    },
+    ops::IntoJava,
+    Error, IntoRust, Jvm, JvmOp, Local, LocalResult,
+};
+
+/// A possibly null Java object.
+type Value<'jvm> = Option<Local<'jvm, Object>>;
+
+impl<'jvm> ser::Error for Error<Local<'jvm, Throwable>> {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Serde(msg.to_string())
+    }
+}
+
+impl<'jvm> de::Error for Error<Local<'jvm, Throwable>> {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Serde(msg.to_string())
+    }
+}
+
+/// Converts `value` into a graph of Java collections, strings and boxed primitives. See the [module docs](self) for the mapping.
+///
+/// The operation produces `None` for values that map to `null`, like `None` or `()`.
+pub fn to_java<T>(value: &T) -> ToJavaValue<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    ToJavaValue { value }
+}
+
+/// Converts a Rust value into Java, see [`to_java`].
+pub struct ToJavaValue<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+impl<T: ?Sized> Clone for ToJavaValue<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ToJavaValue<'_, T> {}
+
+impl<T> JvmOp for ToJavaValue<'_, T>
+where
+    T: Serialize + ?Sized,
+{
+    type Output<'jvm> = Value<'jvm>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, Self::Output<'jvm>> {
+        self.value.serialize(Serializer { jvm })
+    }
+}
+
+/// Converts a graph of Java maps, collections, strings and boxed primitives into any [`Deserialize`](`::serde::Deserialize`) type.
+/// See the [module docs](self) for the mapping.
+///
+/// ```rust,ignore
+/// let config: Config = duchess::serde::from_java(service.get_config()).execute()?;
+/// ```
+pub fn from_java<T>(
+    object: impl IntoJava<Object>,
+) -> impl for<'jvm> JvmOp<Output<'jvm> = Deserialized<T>>
+where
+    T: DeserializeOwned,
+{
+    FromJava {
+        object: object.into_op(),
+        phantom: PhantomData::<fn() -> T>,
+    }
+}
+
+/// A value deserialized by [`from_java`], which converts into `T` when executed.
+pub struct Deserialized<T>(pub T);
+
+impl<T> IntoRust<T> for Deserialized<T> {
+    fn into_rust<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, T> {
+        Ok(self.0)
+    }
+}
+
+#[derive_where::derive_where(Clone)]
+struct FromJava<J, T>
+where
+    J: Clone,
+{
+    object: J,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<J, T> JvmOp for FromJava<J, T>
+where
+    J: crate::jvm::JvmRefOp<Object>,
+    T: DeserializeOwned,
+{
+    type Output<'jvm> = Deserialized<T>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> LocalResult<'jvm, Self::Output<'jvm>> {
+        let object = self.object.into_as_jref(jvm)?;
+        let value = crate::AsJRef::<Object>::as_jref(&object)
+            .ok()
+            .map(|object| jvm.local(object));
+        T::deserialize(Deserializer { jvm, value }).map(Deserialized)
+    }
+}
+
+fn upcast<'jvm, T>(local: Option<Local<'jvm, T>>) -> Value<'jvm>
+where
+    T: crate::semver_unstable::Upcast<Object>,
+{
+    local.map(Local::upcast)
+}
+
+struct Serializer<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+}
+
+impl<'a, 'jvm> ser::Serializer for Serializer<'a, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+    type SerializeSeq = SerializeList<'a, 'jvm>;
+    type SerializeTuple = SerializeList<'a, 'jvm>;
+    type SerializeTupleStruct = SerializeList<'a, 'jvm>;
+    type SerializeTupleVariant = SerializeVariant<SerializeList<'a, 'jvm>>;
+    type SerializeMap = SerializeMap<'a, 'jvm>;
+    type SerializeStruct = SerializeMap<'a, 'jvm>;
+    type SerializeStructVariant = SerializeVariant<SerializeMap<'a, 'jvm>>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Boolean::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Byte::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Short::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Integer::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(long_value_of(self.jvm, v)?))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i16(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i32(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Err(ser::Error::custom(format!(
+                "`{v}` is too large for a Java `long`"
+            ))),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Float::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(upcast(java::lang::Double::value_of(v).do_jni(self.jvm)?))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        let mut utf16 = [0; 2];
+        match *v.encode_utf16(&mut utf16) {
+            [c] => Ok(upcast(java::lang::Character::value_of(c).do_jni(self.jvm)?)),
+            // A Java `char` can't hold characters outside of the Basic Multilingual Plane
+            _ => self.serialize_str(v.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(v.do_jni(self.jvm)?.upcast()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        let bytes: Vec<i8> = v.iter().map(|&b| b as i8).collect();
+        Ok(Some(bytes.as_slice().do_jni(self.jvm)?.upcast()))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let content = value.serialize(Serializer {
+            jvm: &mut *self.jvm,
+        })?;
+        single_entry_map(self.jvm, variant, content)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let list = ArrayList::new().do_jni(self.jvm)?;
+        Ok(SerializeList {
+            jvm: self.jvm,
+            list,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeVariant {
+            variant,
+            content: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let map = HashMap::new().do_jni(self.jvm)?;
+        Ok(SerializeMap {
+            jvm: self.jvm,
+            map,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeVariant {
+            variant,
+            content: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+/// Creates a `HashMap` with a single entry, used for enum variants with content.
+fn single_entry_map<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    key: &str,
+    value: Value<'jvm>,
+) -> LocalResult<'jvm, Value<'jvm>> {
+    let map: Local<'_, HashMap<Object, Object>> = HashMap::new().do_jni(jvm)?;
+    let key: Value<'_> = Some(key.do_jni(jvm)?.upcast());
+    map.put(&key, &value).do_jni(jvm)?;
+    Ok(Some(map.upcast()))
+}
+
+struct SerializeList<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    list: Local<'jvm, ArrayList<Object>>,
+}
+
+impl<'jvm> SerializeList<'_, 'jvm> {
+    fn push<T>(&mut self, value: &T) -> LocalResult<'jvm, ()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(Serializer {
+            jvm: &mut *self.jvm,
+        })?;
+        self.list.add(&value).do_jni(self.jvm)?;
+        Ok(())
+    }
+}
+
+impl<'jvm> ser::SerializeSeq for SerializeList<'_, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(self.list.upcast()))
+    }
+}
+
+impl<'jvm> ser::SerializeTuple for SerializeList<'_, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'jvm> ser::SerializeTupleStruct for SerializeList<'_, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct SerializeMap<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    map: Local<'jvm, HashMap<Object, Object>>,
+    /// The key given to `serialize_key`, waiting for its value.
+    key: Value<'jvm>,
+}
+
+impl<'jvm> SerializeMap<'_, 'jvm> {
+    fn put<T>(&mut self, key: Value<'jvm>, value: &T) -> LocalResult<'jvm, ()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(Serializer {
+            jvm: &mut *self.jvm,
+        })?;
+        self.map.put(&key, &value).do_jni(self.jvm)?;
+        Ok(())
+    }
+}
+
+impl<'jvm> ser::SerializeMap for SerializeMap<'_, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key = key.serialize(Serializer {
+            jvm: &mut *self.jvm,
+        })?;
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.key.take();
+        self.put(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(self.map.upcast()))
+    }
+}
+
+impl<'jvm> ser::SerializeStruct for SerializeMap<'_, 'jvm> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = Some(key.do_jni(self.jvm)?.upcast());
+        self.put(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+/// Serializes the content of an enum variant, then wraps it in a map from the variant name.
+struct SerializeVariant<S> {
+    variant: &'static str,
+    content: S,
+}
+
+impl<'jvm> ser::SerializeTupleVariant for SerializeVariant<SerializeList<'_, 'jvm>> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.content.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let content = Some(self.content.list.upcast());
+        single_entry_map(self.content.jvm, self.variant, content)
+    }
+}
+
+impl<'jvm> ser::SerializeStructVariant for SerializeVariant<SerializeMap<'_, 'jvm>> {
+    type Ok = Value<'jvm>;
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        ser::SerializeStruct::serialize_field(&mut self.content, key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let content = Some(self.content.map.upcast());
+        single_entry_map(self.content.jvm, self.variant, content)
+    }
+}
+
+struct Deserializer<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    value: Value<'jvm>,
+}
+
+impl<'de, 'jvm> de::Deserializer<'de> for Deserializer<'_, 'jvm> {
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let Deserializer { jvm, value } = self;
+        let Some(object) = value else {
+            return visitor.visit_unit();
+        };
+
+        macro_rules! downcast {
+            ($($class:ty => |$local:ident| $visit:expr,)*) => {
+                $(
+                    if let Ok($local) = (&object).try_downcast::<$class>().do_jni(jvm)? {
+                        return $visit;
+                    }
+                )*
+            };
+        }
+
+        downcast! {
+            java::lang::String => |string| visitor.visit_string(string.into_rust(jvm)?),
+            java::lang::Boolean => |boolean| visitor.visit_bool(boolean.boolean_value().do_jni(jvm)?),
+            java::lang::Character => |character| {
+                let c = character.char_value().do_jni(jvm)?;
+                match char::from_u32(c.into()) {
+                    Some(c) => visitor.visit_char(c),
+                    None => Err(de::Error::custom(format!("unpaired surrogate `{c:#x}`"))),
+                }
+            },
+            java::lang::Byte => |number| visitor.visit_i8(number.byte_value().do_jni(jvm)?),
+            java::lang::Short => |number| visitor.visit_i16(number.short_value().do_jni(jvm)?),
+            java::lang::Integer => |number| visitor.visit_i32(number.int_value().do_jni(jvm)?),
+            java::lang::Long => |number| visitor.visit_i64(number.long_value().do_jni(jvm)?),
+            java::lang::Float => |number| visitor.visit_f32(number.float_value().do_jni(jvm)?),
+            java::lang::Double => |number| visitor.visit_f64(number.double_value().do_jni(jvm)?),
+            java::util::Map<Object, Object> => |map| {
+                let keys = map.key_set().to_array().assert_not_null().do_jni(jvm)?;
+                let len = keys.length().do_jni(jvm)?;
+                visitor.visit_map(MapAccess {
+                    jvm,
+                    map,
+                    keys,
+                    index: 0,
+                    len,
+                    value: None,
+                })
+            },
+            java::util::Collection<Object> => |collection| {
+                let elements = collection.to_array().assert_not_null().do_jni(jvm)?;
+                let len = elements.length().do_jni(jvm)?;
+                visitor.visit_seq(SeqAccess {
+                    jvm,
+                    elements,
+                    index: 0,
+                    len,
+                })
+            },
+            java::Array<i8> => |bytes| {
+                let bytes: Vec<i8> = bytes.into_rust(jvm)?;
+                visitor.visit_byte_buf(bytes.into_iter().map(|b| b as u8).collect())
+            },
+        }
+
+        let description: String = object.to_string().assert_not_null().execute_with(jvm)?;
+        Err(de::Error::custom(format!(
+            "cannot deserialize `{description}`, which is not a string, boxed primitive, map, collection or byte array"
+        )))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            None => visitor.visit_none(),
+            Some(_) => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let Deserializer { jvm, value } = self;
+        let Some(object) = value else {
+            return Err(de::Error::custom("expected an enum variant, found null"));
+        };
+
+        if let Ok(string) = (&object).try_downcast::<java::lang::String>().do_jni(jvm)? {
+            let variant: String = string.into_rust(jvm)?;
+            return visitor.visit_enum(variant.into_deserializer());
+        }
+
+        if let Ok(map) = (&object)
+            .try_downcast::<java::util::Map<Object, Object>>()
+            .do_jni(jvm)?
+        {
+            let keys = map.key_set().to_array().assert_not_null().do_jni(jvm)?;
+            if keys.length().do_jni(jvm)? == 1 {
+                let key = keys.get(0).do_jni(jvm)?;
+                let content = map.get(&key).do_jni(jvm)?;
+                let variant: Option<String> = match key {
+                    Some(key) => key
+                        .try_downcast::<java::lang::String>()
+                        .do_jni(jvm)?
+                        .ok()
+                        .map(|key| key.into_rust(jvm))
+                        .transpose()?,
+                    None => None,
+                };
+                if let Some(variant) = variant {
+                    return visitor.visit_enum(EnumAccess {
+                        jvm,
+                        variant,
+                        content,
+                    });
+                }
+            }
+        }
+
+        Err(de::Error::custom(
+            "expected an enum variant, which is a string or a map with a single string key",
+        ))
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct SeqAccess<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    elements: Local<'jvm, java::Array<Object>>,
+    index: i32,
+    len: i32,
+}
+
+impl<'de, 'jvm> de::SeqAccess<'de> for SeqAccess<'_, 'jvm> {
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let value = self.elements.get(self.index).do_jni(self.jvm)?;
+        self.index += 1;
+        seed.deserialize(Deserializer {
+            jvm: &mut *self.jvm,
+            value,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+struct MapAccess<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    map: Local<'jvm, java::util::Map<Object, Object>>,
+    keys: Local<'jvm, java::Array<Object>>,
+    index: i32,
+    len: i32,
+    /// The value for the key returned by the last call to `next_key_seed`.
+    value: Value<'jvm>,
+}
+
+impl<'de, 'jvm> de::MapAccess<'de> for MapAccess<'_, 'jvm> {
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        let key = self.keys.get(self.index).do_jni(self.jvm)?;
+        self.index += 1;
+        self.value = self.map.get(&key).do_jni(self.jvm)?;
+        seed.deserialize(Deserializer {
+            jvm: &mut *self.jvm,
+            value: key,
+        })
+        .map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(Deserializer {
+            jvm: &mut *self.jvm,
+            value: self.value.take(),
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+struct EnumAccess<'a, 'jvm> {
+    jvm: &'a mut Jvm<'jvm>,
+    variant: String,
+    content: Value<'jvm>,
+}
+
+impl<'de, 'a, 'jvm> de::EnumAccess<'de> for EnumAccess<'a, 'jvm> {
+    type Error = Error<Local<'jvm, Throwable>>;
+    type Variant = Deserializer<'a, 'jvm>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant: de::value::StringDeserializer<Self::Error> = self.variant.into_deserializer();
+        let variant = seed.deserialize(variant)?;
+        Ok((
+            variant,
+            Deserializer {
+                jvm: self.jvm,
+                value: self.content,
+            },
+        ))
+    }
+}
+
+impl<'de, 'jvm> de::VariantAccess<'de> for Deserializer<'_, 'jvm> {
+    type Error = Error<Local<'jvm, Throwable>>;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
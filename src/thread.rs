@@ -1,14 +1,87 @@
 use std::cell::Cell;
 
+use once_cell::sync::OnceCell;
+
 use crate::{
+    lifecycle,
     raw::{EnvPtr, JvmPtr},
     Error, Result,
 };
 
+/// How a thread that is not yet attached to the JVM is attached when it first uses it.
+/// Set for all threads with [`JvmBuilder::attach_policy`](`crate::jvm::JvmBuilder::attach_policy`),
+/// or for one thread with [`AttachPolicy::set_for_current_thread`].
+///
+/// Whatever the policy, the Java thread is named after the Rust thread (see [`std::thread::Builder::name`]),
+/// so that it can be recognized in Java thread dumps.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AttachPolicy {
+    /// Attach the thread for the duration of each top-level operation and detach it afterwards.
+    /// This is cheap for threads that rarely use the JVM, but expensive for thread pools that use it often.
+    #[default]
+    PerCall,
+
+    /// Attach the thread the first time it uses the JVM and keep it attached until the thread exits.
+    /// Like any non-daemon Java thread, it keeps [`Jvm::shutdown`](`crate::Jvm::shutdown`) waiting while attached.
+    Permanent,
+
+    /// Like [`AttachPolicy::Permanent`], but attach the thread as a daemon thread with
+    /// `AttachCurrentThreadAsDaemon`, so it doesn't keep the JVM from being destroyed.
+    Daemon,
+}
+
+impl AttachPolicy {
+    /// Uses this policy instead of the one set on the [`JvmBuilder`](`crate::jvm::JvmBuilder`)
+    /// whenever the current thread is attached from now on, e.g. to keep the worker threads of
+    /// a pool attached while other threads attach per call.
+    ///
+    /// This does not change how the thread is attached right now: a thread that is already attached
+    /// permanently stays attached until it exits, whatever its new policy.
+    pub fn set_for_current_thread(self) {
+        THREAD_ATTACH_POLICY.with(|policy| policy.set(Some(self)));
+    }
+}
+
+static ATTACH_POLICY: OnceCell<AttachPolicy> = OnceCell::new();
+
+/// Sets the policy used by [`attach`]. Only the first call has any effect.
+pub(crate) fn set_attach_policy(policy: AttachPolicy) {
+    let _ = ATTACH_POLICY.set(policy);
+}
+
+/// The policy for the current thread: its own if it has one, otherwise the process-wide one.
+pub(crate) fn attach_policy() -> AttachPolicy {
+    THREAD_ATTACH_POLICY
+        .with(Cell::get)
+        .unwrap_or_else(|| ATTACH_POLICY.get().copied().unwrap_or_default())
+}
+
 // XX: The current thread-local state will prevent duchess => java => duchess call stacks. We may want to relax this in
 // the future!
 thread_local! {
     static STATE: Cell<State> = Cell::new(State::Detached);
+
+    /// Set by [`AttachPolicy::set_for_current_thread`] to override the process-wide policy.
+    static THREAD_ATTACH_POLICY: Cell<Option<AttachPolicy>> = const { Cell::new(None) };
+
+    /// Set when duchess permanently attached this thread, so that it is detached again when the thread exits.
+    static PERMANENT_ATTACHMENT: Cell<Option<PermanentAttachment>> = const { Cell::new(None) };
+}
+
+struct PermanentAttachment(JvmPtr);
+
+impl Drop for PermanentAttachment {
+    fn drop(&mut self) {
+        // Once the JVM is destroyed there is nothing left to detach from. While it is shutting down we
+        // still detach, as `DestroyJavaVM` would otherwise wait for this thread forever.
+        if lifecycle::is_shut_down() {
+            return;
+        }
+        if let Err(err) = unsafe { self.0.detach_thread() } {
+            tracing::warn!(?err, "couldn't detach exiting thread from JVM");
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -21,6 +94,16 @@ pub enum State {
     Detached,
 }
 
+/// Returns true if the current thread is inside a duchess frame, e.g. in a call to `Jvm::with`.
+pub(crate) fn in_use() -> bool {
+    STATE.with(|state| {
+        let current = state.replace(State::InUse);
+        let in_use = current == State::InUse;
+        state.set(current);
+        in_use
+    })
+}
+
 fn attached_or(jvm: JvmPtr, f: impl FnOnce() -> Result<AttachGuard>) -> Result<AttachGuard> {
     STATE.with(|state| match state.replace(State::InUse) {
         State::AttachedPermanently(env) => Ok(AttachGuard {
@@ -87,26 +170,42 @@ impl Drop for JniCallbackGuard<'_> {
     }
 }
 
-pub fn attach_permanently(jvm: JvmPtr) -> Result<AttachGuard> {
+/// Attaches the current thread until it exits, as a daemon thread if `daemon` is true.
+pub fn attach_permanently(jvm: JvmPtr, daemon: bool) -> Result<AttachGuard> {
     attached_or(jvm, || {
+        // SAFETY: the thread stays attached until `PERMANENT_ATTACHMENT` is dropped at thread exit
+        let env = match unsafe { jvm.env()? } {
+            // attached outside of duchess, so leave detaching to whoever attached it
+            Some(env) => env,
+            None => {
+                let env = unsafe { jvm.attach_thread(daemon)? };
+                PERMANENT_ATTACHMENT
+                    .with(|attachment| attachment.set(Some(PermanentAttachment(jvm))));
+                env
+            }
+        };
         Ok(AttachGuard {
             jvm,
-            // no-op if already attached outside of duchess
-            env: unsafe { jvm.attach_thread()? },
+            env,
             permanent: true,
         })
     })
 }
 
+/// Attaches the current thread according to the [`AttachPolicy`].
 pub unsafe fn attach<'jvm>(jvm: JvmPtr) -> Result<AttachGuard> {
-    attached_or(jvm, || {
-        Ok(AttachGuard {
-            jvm,
-            // no-op if already attached outside of duchess
-            env: unsafe { jvm.attach_thread()? },
-            permanent: false,
-        })
-    })
+    match attach_policy() {
+        AttachPolicy::PerCall => attached_or(jvm, || {
+            Ok(AttachGuard {
+                jvm,
+                // no-op if already attached outside of duchess
+                env: unsafe { jvm.attach_thread(false)? },
+                permanent: false,
+            })
+        }),
+        AttachPolicy::Permanent => attach_permanently(jvm, false),
+        AttachPolicy::Daemon => attach_permanently(jvm, true),
+    }
 }
 
 /// When dropped, will detach the current thread from the JVM unless it was permanently attached.
@@ -0,0 +1,129 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    cast::Upcast,
+    java::{self, time::Instant},
+    jvm::JavaObjectExt,
+    semver_unstable::ToJavaImpl,
+    Error, IntoRust, Jvm, JvmOp, Local,
+};
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+/// The range of `java.time.Instant` in seconds from the epoch,
+/// from `-1000000000-01-01T00:00Z` to `1000000000-12-31T23:59:59.999999999Z`.
+const INSTANT_SECONDS: std::ops::RangeInclusive<i64> =
+    -31_557_014_167_219_200..=31_556_889_864_403_199;
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
+/// The error for a Rust `value` that `target` cannot represent.
+fn out_of_range<'jvm>(
+    value: &impl std::fmt::Debug,
+    target: &'static str,
+) -> Error<Local<'jvm, java::lang::Throwable>> {
+    Error::TimeOutOfRange {
+        value: format!("{value:?}"),
+        target,
+    }
+}
+
+/// Fails with the error for a Java `value` that `target` cannot represent, described by its `toString`.
+fn java_out_of_range<'jvm, T>(
+    jvm: &mut Jvm<'jvm>,
+    value: &impl Upcast<java::lang::Object>,
+    target: &'static str,
+) -> crate::LocalResult<'jvm, T> {
+    // SAFETY: `Upcast` guarantees that `value` is also an `Object`
+    let value: &java::lang::Object = unsafe { value.as_raw().as_ref() };
+    let value: String = value
+        .to_string()
+        .assert_not_null()
+        .do_jni(jvm)?
+        .into_rust(jvm)?;
+    Err(Error::TimeOutOfRange { value, target })
+}
+
+/// Reads the seconds from the epoch and the nanoseconds within that second (always positive) of an `Instant`.
+fn instant_parts<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    instant: &Instant,
+) -> crate::LocalResult<'jvm, (i64, u32)> {
+    let seconds = instant.get_epoch_second().do_jni(jvm)?;
+    let nanos = instant.get_nano().do_jni(jvm)?;
+    // `getNano` is always in `0..1_000_000_000`
+    Ok((seconds, nanos as u32))
+}
+
+/// Fails with [`Error::TimeOutOfRange`] for a time more than a billion years away from the epoch.
+impl ToJavaImpl<Instant> for SystemTime {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, Instant>>> {
+        let (seconds, nanos) = match rust.duration_since(UNIX_EPOCH) {
+            Ok(after) => (i64::try_from(after.as_secs()).ok(), after.subsec_nanos()),
+            // Before the epoch, borrow a second so that the nanoseconds stay positive like Java's
+            Err(before) => {
+                let before = before.duration();
+                let seconds = i64::try_from(before.as_secs()).ok().map(|s| -s);
+                match before.subsec_nanos() {
+                    0 => (seconds, 0),
+                    nanos => (
+                        seconds.and_then(|s| s.checked_sub(1)),
+                        NANOS_PER_SECOND - nanos,
+                    ),
+                }
+            }
+        };
+        match seconds.filter(|s| INSTANT_SECONDS.contains(s)) {
+            Some(seconds) => Instant::of_epoch_second(seconds, i64::from(nanos)).do_jni(jvm),
+            None => Err(out_of_range(rust, "java.time.Instant")),
+        }
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`] for an `Instant` that the platform's `SystemTime` cannot represent.
+impl IntoRust<SystemTime> for &Instant {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, SystemTime> {
+        let (seconds, nanos) = instant_parts(jvm, self)?;
+        let time = if seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(seconds.unsigned_abs(), nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+                .and_then(|t| t.checked_add(Duration::from_nanos(nanos.into())))
+        };
+        match time {
+            Some(time) => Ok(time),
+            None => java_out_of_range(jvm, self, "std::time::SystemTime"),
+        }
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`] for more than `i64::MAX` seconds.
+impl ToJavaImpl<java::time::Duration> for Duration {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::time::Duration>>> {
+        let Ok(seconds) = i64::try_from(rust.as_secs()) else {
+            return Err(out_of_range(rust, "java.time.Duration"));
+        };
+        java::time::Duration::of_seconds(seconds, i64::from(rust.subsec_nanos())).do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`] for a negative `Duration`.
+impl IntoRust<Duration> for &java::time::Duration {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Duration> {
+        let seconds = self.get_seconds().do_jni(jvm)?;
+        let nanos = self.get_nano().do_jni(jvm)?;
+        match u64::try_from(seconds) {
+            // `getNano` is always in `0..1_000_000_000`, even for negative durations
+            Ok(seconds) => Ok(Duration::new(seconds, nanos as u32)),
+            Err(_) => java_out_of_range(jvm, self, "std::time::Duration"),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Conversions between `java.time` and the [`chrono`] types, enabled by the `chrono` feature.
+//!
+//! Java has no leap seconds, so a `chrono` time within one fails with
+//! [`Error::TimeOutOfRange`](`crate::Error::TimeOutOfRange`).
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Timelike, Utc,
+};
+
+use super::{instant_parts, java_out_of_range, out_of_range, NANOS_PER_SECOND};
+use crate::{
+    java::{
+        self,
+        time::{Instant, LocalDate, LocalDateTime, ZoneOffset, ZonedDateTime},
+    },
+    semver_unstable::ToJavaImpl,
+    IntoRust, Jvm, JvmOp, Local,
+};
+
+/// Creates the `Instant` of `time`; any `chrono` date is within the range of `Instant`.
+fn instant_of<'jvm, Tz: TimeZone>(
+    jvm: &mut Jvm<'jvm>,
+    time: &DateTime<Tz>,
+) -> crate::LocalResult<'jvm, Local<'jvm, Instant>> {
+    let nanos = time.timestamp_subsec_nanos();
+    if nanos >= NANOS_PER_SECOND {
+        return Err(out_of_range(&time.naive_utc(), "java.time.Instant"));
+    }
+    Instant::of_epoch_second(time.timestamp(), i64::from(nanos))
+        .assert_not_null()
+        .do_jni(jvm)
+}
+
+/// Converts an `Instant` into a UTC `DateTime`, failing for one that is outside the range of `chrono`.
+fn date_time_of<'jvm>(
+    jvm: &mut Jvm<'jvm>,
+    instant: &Instant,
+) -> crate::LocalResult<'jvm, DateTime<Utc>> {
+    let (seconds, nanos) = instant_parts(jvm, instant)?;
+    match DateTime::from_timestamp(seconds, nanos) {
+        Some(time) => Ok(time),
+        None => java_out_of_range(jvm, instant, "chrono::DateTime"),
+    }
+}
+
+impl<Tz: TimeZone> ToJavaImpl<Instant> for DateTime<Tz> {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, Instant>>> {
+        instant_of(jvm, rust).map(Some)
+    }
+}
+
+impl IntoRust<DateTime<Utc>> for &Instant {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, DateTime<Utc>> {
+        date_time_of(jvm, self)
+    }
+}
+
+/// The `ZonedDateTime` has a fixed `ZoneOffset` as its zone, because a `chrono` time zone has no Java name.
+impl<Tz: TimeZone> ToJavaImpl<ZonedDateTime> for DateTime<Tz> {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, ZonedDateTime>>> {
+        let instant = instant_of(jvm, rust)?;
+        let offset = ZoneOffset::of_total_seconds(rust.offset().fix().local_minus_utc())
+            .assert_not_null()
+            .do_jni(jvm)?;
+        ZonedDateTime::of_instant(&instant, &offset).do_jni(jvm)
+    }
+}
+
+/// The time zone is lost, but the offset from UTC at that instant is kept.
+impl IntoRust<DateTime<FixedOffset>> for &ZonedDateTime {
+    fn into_rust<'jvm>(
+        self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, DateTime<FixedOffset>> {
+        let instant = Instant::from(self).assert_not_null().do_jni(jvm)?;
+        let time = date_time_of(jvm, &instant)?;
+        let offset = self.get_offset().assert_not_null().do_jni(jvm)?;
+        let offset = offset.get_total_seconds().do_jni(jvm)?;
+        match FixedOffset::east_opt(offset) {
+            Some(offset) => Ok(time.with_timezone(&offset)),
+            None => java_out_of_range(jvm, self, "chrono::FixedOffset"),
+        }
+    }
+}
+
+impl IntoRust<DateTime<Utc>> for &ZonedDateTime {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, DateTime<Utc>> {
+        let instant = Instant::from(self).assert_not_null().do_jni(jvm)?;
+        date_time_of(jvm, &instant)
+    }
+}
+
+impl ToJavaImpl<java::time::Duration> for TimeDelta {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::time::Duration>>> {
+        // `subsec_nanos` has the sign of the whole delta, which `ofSeconds` normalizes
+        java::time::Duration::of_seconds(rust.num_seconds(), i64::from(rust.subsec_nanos()))
+            .do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`](`crate::Error::TimeOutOfRange`) for more than `i64::MAX` milliseconds.
+impl IntoRust<TimeDelta> for &java::time::Duration {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, TimeDelta> {
+        let seconds = self.get_seconds().do_jni(jvm)?;
+        let nanos = self.get_nano().do_jni(jvm)?;
+        // `getNano` is always in `0..1_000_000_000`, even for negative durations
+        match TimeDelta::new(seconds, nanos as u32) {
+            Some(delta) => Ok(delta),
+            None => java_out_of_range(jvm, self, "chrono::TimeDelta"),
+        }
+    }
+}
+
+impl ToJavaImpl<LocalDate> for NaiveDate {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, LocalDate>>> {
+        // Any `NaiveDate` is within the range of `LocalDate`
+        LocalDate::of(rust.year(), rust.month() as i32, rust.day() as i32).do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`](`crate::Error::TimeOutOfRange`) for a year outside the range of `NaiveDate`.
+impl IntoRust<NaiveDate> for &LocalDate {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, NaiveDate> {
+        let year = self.get_year().do_jni(jvm)?;
+        let month = self.get_month_value().do_jni(jvm)?;
+        let day = self.get_day_of_month().do_jni(jvm)?;
+        match NaiveDate::from_ymd_opt(year, month as u32, day as u32) {
+            Some(date) => Ok(date),
+            None => java_out_of_range(jvm, self, "chrono::NaiveDate"),
+        }
+    }
+}
+
+impl ToJavaImpl<LocalDateTime> for NaiveDateTime {
+    fn to_java_impl<'jvm>(
+        rust: &Self,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, LocalDateTime>>> {
+        if rust.nanosecond() >= NANOS_PER_SECOND {
+            return Err(out_of_range(rust, "java.time.LocalDateTime"));
+        }
+        LocalDateTime::of(
+            rust.year(),
+            rust.month() as i32,
+            rust.day() as i32,
+            rust.hour() as i32,
+            rust.minute() as i32,
+            rust.second() as i32,
+            rust.nanosecond() as i32,
+        )
+        .do_jni(jvm)
+    }
+}
+
+/// Fails with [`Error::TimeOutOfRange`](`crate::Error::TimeOutOfRange`) for a year outside the range of `NaiveDate`.
+impl IntoRust<NaiveDateTime> for &LocalDateTime {
+    fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, NaiveDateTime> {
+        let date: NaiveDate = self
+            .to_local_date()
+            .assert_not_null()
+            .do_jni(jvm)?
+            .into_rust(jvm)?;
+        let hour = self.get_hour().do_jni(jvm)?;
+        let minute = self.get_minute().do_jni(jvm)?;
+        let second = self.get_second().do_jni(jvm)?;
+        let nano = self.get_nano().do_jni(jvm)?;
+        // Java's fields are always valid times of day
+        let time =
+            NaiveTime::from_hms_nano_opt(hour as u32, minute as u32, second as u32, nano as u32)
+                .expect("valid `LocalTime`");
+        Ok(date.and_time(time))
+    }
+}
@@ -1,6 +1,12 @@
-use std::{collections::HashMap, marker::PhantomData};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    marker::PhantomData,
+};
 
-use crate::{cast::Upcast, from_ref::FromRef, java, jvm::JavaView, Error, Java, Jvm, JvmOp, Local};
+use crate::{
+    cast::Upcast, error::rust_error_to_java, from_ref::FromRef, java, jvm::JavaView, Java, Jvm,
+    JvmOp, Local,
+};
 
 use crate::jvm::JavaScalar;
 
@@ -77,80 +83,127 @@ where
     }
 }
 
-impl<K, V, JK, JV, S> ToJavaImpl<java::util::HashMap<JK, JV>> for HashMap<K, V, S>
-where
-    K: ToJavaImpl<JK>,
-    V: ToJavaImpl<JV>,
-    JK: Upcast<java::lang::Object> + Upcast<JK>,
-    JV: Upcast<java::lang::Object> + Upcast<JV>,
-{
-    fn to_java_impl<'jvm>(
-        rust: &Self,
-        jvm: &mut Jvm<'jvm>,
-    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::HashMap<JK, JV>>>> {
-        let jmap: Local<'jvm, java::util::HashMap<JK, JV>> =
-            java::util::HashMap::new().do_jni(jvm)?;
-        for (key, value) in rust {
-            jmap.put(key.to_java(), value.to_java()).do_jni(jvm)?;
-        }
-        Ok(Some(jmap))
-    }
+/// Implements [`ToJavaImpl`] for Rust collections, which become a new instance of the Java class `$class`
+/// filled with `add`.
+macro_rules! collection_to_java {
+    ($($rust:ident<E $(, $S:ident)?> => $class:ident,)*) => {
+        $(
+            impl<E, JE $(, $S)?> ToJavaImpl<java::util::$class<JE>> for $rust<E $(, $S)?>
+            where
+                E: ToJavaImpl<JE>,
+                JE: Upcast<java::lang::Object> + Upcast<JE>,
+            {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::$class<JE>>>> {
+                    let collection: Local<'jvm, java::util::$class<JE>> =
+                        java::util::$class::new().do_jni(jvm)?;
+                    for element in rust {
+                        collection.add(element.to_java()).do_jni(jvm)?;
+                    }
+                    Ok(Some(collection))
+                }
+            }
+        )*
+    };
 }
 
-impl<K, V, JK, JV, S> ToJavaImpl<java::util::Map<JK, JV>> for HashMap<K, V, S>
-where
-    K: ToJavaImpl<JK>,
-    V: ToJavaImpl<JV>,
-    JK: Upcast<java::lang::Object> + Upcast<JK>,
-    JV: Upcast<java::lang::Object> + Upcast<JV>,
-{
-    fn to_java_impl<'jvm>(
-        rust: &Self,
-        jvm: &mut Jvm<'jvm>,
-    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::Map<JK, JV>>>> {
-        Ok(Some(
-            rust.to_java::<java::util::HashMap<JK, JV>>()
-                .assert_not_null()
-                .upcast()
-                .do_jni(jvm)?,
-        ))
-    }
+collection_to_java! {
+    Vec<E> => ArrayList,
+    VecDeque<E> => ArrayList,
+    HashSet<E, S> => HashSet,
+    BTreeSet<E> => LinkedHashSet,
 }
 
-impl<E, JE> ToJavaImpl<java::util::ArrayList<JE>> for Vec<E>
-where
-    E: ToJavaImpl<JE>,
-    JE: Upcast<java::lang::Object> + Upcast<JE>,
-{
-    fn to_java_impl<'jvm>(
-        rust: &Self,
-        jvm: &mut Jvm<'jvm>,
-    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::ArrayList<JE>>>> {
-        let jvec: Local<'jvm, java::util::ArrayList<JE>> =
-            java::util::ArrayList::new().do_jni(jvm)?;
-        for element in rust {
-            jvec.add(element.to_java()).do_jni(jvm)?;
-        }
-        Ok(Some(jvec))
-    }
+/// Implements [`ToJavaImpl`] into the Java interface `$interface` by upcasting the class `$class`
+/// that the Rust collection converts into.
+macro_rules! collection_to_java_interface {
+    ($($rust:ident<E $(, $S:ident)?> => $class:ident: $interface:ident,)*) => {
+        $(
+            impl<E, JE $(, $S)?> ToJavaImpl<java::util::$interface<JE>> for $rust<E $(, $S)?>
+            where
+                E: ToJavaImpl<JE>,
+                JE: Upcast<java::lang::Object> + Upcast<JE>,
+            {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::$interface<JE>>>> {
+                    Ok(Some(
+                        rust.to_java::<java::util::$class<JE>>()
+                            .assert_not_null()
+                            .upcast()
+                            .do_jni(jvm)?,
+                    ))
+                }
+            }
+        )*
+    };
 }
 
-impl<E, JE> ToJavaImpl<java::util::List<JE>> for Vec<E>
-where
-    E: ToJavaImpl<JE>,
-    JE: Upcast<java::lang::Object> + Upcast<JE>,
-{
-    fn to_java_impl<'jvm>(
-        rust: &Self,
-        jvm: &mut Jvm<'jvm>,
-    ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::List<JE>>>> {
-        Ok(Some(
-            rust.to_java::<java::util::ArrayList<JE>>()
-                .assert_not_null()
-                .upcast()
-                .do_jni(jvm)?,
-        ))
-    }
+collection_to_java_interface! {
+    Vec<E> => ArrayList: List,
+    Vec<E> => ArrayList: Collection,
+    VecDeque<E> => ArrayList: List,
+    VecDeque<E> => ArrayList: Collection,
+    HashSet<E, S> => HashSet: Set,
+    HashSet<E, S> => HashSet: Collection,
+    BTreeSet<E> => LinkedHashSet: Set,
+    BTreeSet<E> => LinkedHashSet: Collection,
+}
+
+/// Implements [`ToJavaImpl`] for Rust maps, which become a new instance of the Java class `$class`
+/// filled with `put`, or a `java.util.Map`.
+macro_rules! map_to_java {
+    ($($rust:ident<K, V $(, $S:ident)?> => $class:ident,)*) => {
+        $(
+            impl<K, V, JK, JV $(, $S)?> ToJavaImpl<java::util::$class<JK, JV>> for $rust<K, V $(, $S)?>
+            where
+                K: ToJavaImpl<JK>,
+                V: ToJavaImpl<JV>,
+                JK: Upcast<java::lang::Object> + Upcast<JK>,
+                JV: Upcast<java::lang::Object> + Upcast<JV>,
+            {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::$class<JK, JV>>>> {
+                    let jmap: Local<'jvm, java::util::$class<JK, JV>> =
+                        java::util::$class::new().do_jni(jvm)?;
+                    for (key, value) in rust {
+                        jmap.put(key.to_java(), value.to_java()).do_jni(jvm)?;
+                    }
+                    Ok(Some(jmap))
+                }
+            }
+
+            impl<K, V, JK, JV $(, $S)?> ToJavaImpl<java::util::Map<JK, JV>> for $rust<K, V $(, $S)?>
+            where
+                K: ToJavaImpl<JK>,
+                V: ToJavaImpl<JV>,
+                JK: Upcast<java::lang::Object> + Upcast<JK>,
+                JV: Upcast<java::lang::Object> + Upcast<JV>,
+            {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, java::util::Map<JK, JV>>>> {
+                    Ok(Some(
+                        rust.to_java::<java::util::$class<JK, JV>>()
+                            .assert_not_null()
+                            .upcast()
+                            .do_jni(jvm)?,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+map_to_java! {
+    HashMap<K, V, S> => HashMap,
+    BTreeMap<K, V> => LinkedHashMap,
 }
 
 impl ToJavaImpl<java::lang::String> for String {
@@ -246,17 +299,7 @@ where
     ) -> crate::LocalResult<'jvm, Option<Local<'jvm, J>>> {
         match rust {
             Ok(r) => R::to_java_impl(r, jvm),
-            Err(e) => match e {
-                Error::Thrown(t) => Err(Error::Thrown(jvm.local(t))),
-                Error::SliceTooLong(t) => Err(Error::SliceTooLong(*t)),
-                Error::NullDeref => Err(Error::NullDeref),
-                Error::NestedUsage => Err(Error::NestedUsage),
-                Error::JvmAlreadyExists => Err(Error::JvmAlreadyExists),
-                Error::UnableToLoadLibjvm(t) => Err(Error::UnableToLoadLibjvm(
-                    format!("UnableToLoadLibjvm({t:?})").as_str().into(), // FIXME: should to_java_impl be `self` ?
-                )),
-                Error::JvmInternal(t) => Err(Error::JvmInternal(t.clone())),
-            },
+            Err(e) => Err(e.to_local(jvm)),
         }
     }
 }
@@ -272,17 +315,7 @@ where
     ) -> crate::LocalResult<'jvm, Option<Local<'jvm, J>>> {
         match rust {
             Ok(r) => R::to_java_impl(r, jvm),
-            Err(e) => match e {
-                Error::Thrown(t) => Err(Error::Thrown(jvm.local(t))),
-                Error::SliceTooLong(t) => Err(Error::SliceTooLong(*t)),
-                Error::NullDeref => Err(Error::NullDeref),
-                Error::NestedUsage => Err(Error::NestedUsage),
-                Error::JvmAlreadyExists => Err(Error::JvmAlreadyExists),
-                Error::UnableToLoadLibjvm(t) => Err(Error::UnableToLoadLibjvm(
-                    format!("UnableToLoadLibjvm({t:?})").as_str().into(), // FIXME: should to_java_impl be `self` ?
-                )),
-                Error::JvmInternal(t) => Err(Error::JvmInternal(t.clone())),
-            },
+            Err(e) => Err(e.to_local(jvm)),
         }
     }
 }
@@ -302,17 +335,54 @@ where
     fn to_java_scalar<'jvm>(rust: &Self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, J> {
         match rust {
             Ok(r) => R::to_java_scalar(r, jvm),
-            Err(e) => match e {
-                Error::Thrown(t) => Err(Error::Thrown(jvm.local(t))),
-                Error::SliceTooLong(t) => Err(Error::SliceTooLong(*t)),
-                Error::NullDeref => Err(Error::NullDeref),
-                Error::NestedUsage => Err(Error::NestedUsage),
-                Error::JvmAlreadyExists => Err(Error::JvmAlreadyExists),
-                Error::UnableToLoadLibjvm(t) => Err(Error::UnableToLoadLibjvm(
-                    format!("UnableToLoadLibjvm({t:?})").as_str().into(), // FIXME: should to_java_scalar be `self` ?
-                )),
-                Error::JvmInternal(t) => Err(Error::JvmInternal(t.clone())),
-            },
+            Err(e) => Err(e.to_local(jvm)),
         }
     }
 }
+
+/// Implements [`ToJavaImpl`] and [`ToJavaScalar`] for `Result<R, E>` where `E` is a general purpose
+/// Rust error type. An `Err` is raised in Java as an exception whose cause chain mirrors
+/// [`std::error::Error::source`]; see [`rust_error_to_java`] for details.
+macro_rules! rust_error_result {
+    ($($(#[$attr:meta])* $error:ty,)*) => {
+        $(
+            $(#[$attr])*
+            impl<J, R> ToJavaImpl<J> for Result<R, $error>
+            where
+                J: Upcast<java::lang::Object>,
+                R: ToJavaImpl<J>,
+            {
+                fn to_java_impl<'jvm>(
+                    rust: &Self,
+                    jvm: &mut Jvm<'jvm>,
+                ) -> crate::LocalResult<'jvm, Option<Local<'jvm, J>>> {
+                    match rust {
+                        Ok(r) => R::to_java_impl(r, jvm),
+                        Err(e) => Err(rust_error_to_java(jvm, e.as_ref())),
+                    }
+                }
+            }
+
+            $(#[$attr])*
+            impl<J, R> ToJavaScalar<J> for Result<R, $error>
+            where
+                J: JavaScalar,
+                R: ToJavaScalar<J>,
+            {
+                fn to_java_scalar<'jvm>(rust: &Self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, J> {
+                    match rust {
+                        Ok(r) => R::to_java_scalar(r, jvm),
+                        Err(e) => Err(rust_error_to_java(jvm, e.as_ref())),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+rust_error_result! {
+    Box<dyn std::error::Error>,
+    Box<dyn std::error::Error + Send + Sync>,
+    #[cfg(feature = "anyhow")]
+    anyhow::Error,
+}
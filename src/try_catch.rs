@@ -1,13 +1,105 @@
 use std::marker::PhantomData;
 
-use crate::{cast::Upcast, java::lang::Throwable, Jvm, JvmOp, Local};
+use crate::{cast::Upcast, java::lang::Throwable, IntoRust, Jvm, JvmOp, Local};
+
+/// Exception types that can be caught with [`JvmOp::catch`][].
+///
+/// Implemented for any Java exception type `J` (catching produces a `Local<J>`)
+/// and for tuples of exception types such as `(A, B)`, which behave like Java's
+/// `catch (A | B e)`. For tuples, the types are tested in order and the first match
+/// wins, so list subclasses before their superclasses. Catching a tuple produces
+/// one of the [`Caught2`][], [`Caught3`][] or [`Caught4`][] enums.
+pub trait Catch {
+    /// The value produced when an exception is caught.
+    type Caught<'jvm>;
+
+    /// Returns `Ok` if `exception` is an instance of one of the caught types, otherwise gives it back as `Err`.
+    fn try_catch<'jvm>(
+        exception: Local<'jvm, Throwable>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Result<Self::Caught<'jvm>, Local<'jvm, Throwable>>>;
+}
+
+impl<J> Catch for J
+where
+    J: Upcast<Throwable>,
+{
+    type Caught<'jvm> = Local<'jvm, J>;
+
+    fn try_catch<'jvm>(
+        exception: Local<'jvm, Throwable>,
+        jvm: &mut Jvm<'jvm>,
+    ) -> crate::LocalResult<'jvm, Result<Self::Caught<'jvm>, Local<'jvm, Throwable>>> {
+        if let Ok(exception) = exception.try_downcast::<J>().do_jni(jvm)? {
+            Ok(Ok(exception))
+        } else {
+            Ok(Err(exception))
+        }
+    }
+}
+
+macro_rules! caught_enum {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident => $rust:ident,)* }) => {
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq)]
+        pub enum $name<$($variant,)*> {
+            $($variant($variant),)*
+        }
+
+        impl<$($variant,)*> Catch for ($($variant,)*)
+        where
+            $($variant: Upcast<Throwable>,)*
+        {
+            type Caught<'jvm> = $name<$(Local<'jvm, $variant>,)*>;
+
+            fn try_catch<'jvm>(
+                exception: Local<'jvm, Throwable>,
+                jvm: &mut Jvm<'jvm>,
+            ) -> crate::LocalResult<'jvm, Result<Self::Caught<'jvm>, Local<'jvm, Throwable>>> {
+                $(
+                    let exception = match <$variant as Catch>::try_catch(exception, jvm)? {
+                        Ok(caught) => return Ok(Ok($name::$variant(caught))),
+                        Err(exception) => exception,
+                    };
+                )*
+                Ok(Err(exception))
+            }
+        }
+
+        impl<$($variant, $rust,)*> IntoRust<$name<$($rust,)*>> for $name<$($variant,)*>
+        where
+            $($variant: IntoRust<$rust>,)*
+        {
+            fn into_rust<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, $name<$($rust,)*>> {
+                match self {
+                    $($name::$variant(e) => Ok($name::$variant(e.into_rust(jvm)?)),)*
+                }
+            }
+        }
+    };
+}
+
+caught_enum! {
+    /// Exception caught by `catch::<(A, B)>()`.
+    Caught2 { A => RA, B => RB, }
+}
+
+caught_enum! {
+    /// Exception caught by `catch::<(A, B, C)>()`.
+    Caught3 { A => RA, B => RB, C => RC, }
+}
+
+caught_enum! {
+    /// Exception caught by `catch::<(A, B, C, D)>()`.
+    Caught4 { A => RA, B => RB, C => RC, D => RD, }
+}
 
 #[derive_where::derive_where(Clone)]
 #[derive_where(Copy; This: Copy)]
 pub struct TryCatch<This, J>
 where
     This: JvmOp,
-    J: Upcast<Throwable>,
+    J: Catch,
 {
     this: This,
     phantom: PhantomData<J>,
@@ -16,7 +108,7 @@ where
 impl<This, J> TryCatch<This, J>
 where
     This: JvmOp,
-    J: Upcast<Throwable>,
+    J: Catch,
 {
     pub(crate) fn new(this: This) -> Self {
         Self {
@@ -29,23 +121,58 @@ where
 impl<This, J> JvmOp for TryCatch<This, J>
 where
     This: JvmOp,
-    J: Upcast<Throwable>,
+    J: Catch,
 {
-    type Output<'jvm> = Result<This::Output<'jvm>, Local<'jvm, J>>;
+    type Output<'jvm> = Result<This::Output<'jvm>, J::Caught<'jvm>>;
 
     fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
         match self.this.do_jni(jvm) {
             Ok(v) => Ok(Ok(v)),
             Err(e) => match e {
-                crate::Error::Thrown(exception) => {
-                    if let Ok(exception) = exception.try_downcast::<J>().do_jni(jvm)? {
-                        Ok(Err(exception))
-                    } else {
-                        Err(crate::Error::Thrown(exception))
-                    }
-                }
+                crate::Error::Thrown(exception) => match J::try_catch(exception, jvm)? {
+                    Ok(caught) => Ok(Err(caught)),
+                    Err(exception) => Err(crate::Error::Thrown(exception)),
+                },
                 _ => Err(e),
             },
         }
     }
 }
+
+#[derive_where::derive_where(Clone)]
+#[derive_where(Copy; This: Copy, Cleanup: Copy)]
+pub struct Finally<This, Cleanup>
+where
+    This: JvmOp,
+    Cleanup: JvmOp,
+{
+    this: This,
+    cleanup: Cleanup,
+}
+
+impl<This, Cleanup> Finally<This, Cleanup>
+where
+    This: JvmOp,
+    Cleanup: JvmOp,
+{
+    pub(crate) fn new(this: This, cleanup: Cleanup) -> Self {
+        Self { this, cleanup }
+    }
+}
+
+impl<This, Cleanup> JvmOp for Finally<This, Cleanup>
+where
+    This: JvmOp,
+    Cleanup: JvmOp,
+{
+    type Output<'jvm> = This::Output<'jvm>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> crate::LocalResult<'jvm, Self::Output<'jvm>> {
+        let result = self.this.do_jni(jvm);
+
+        // Like a Java `finally` block, an error from the cleanup replaces the result of the operation.
+        self.cleanup.do_jni(jvm)?;
+
+        result
+    }
+}
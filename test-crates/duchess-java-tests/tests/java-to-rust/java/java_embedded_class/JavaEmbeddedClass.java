@@ -0,0 +1,17 @@
+//@check-pass
+package java_embedded_class;
+
+public class JavaEmbeddedClass {
+    native int hiddenAnswer();
+
+    public static void main(String[] args) {
+        System.loadLibrary("native_fn_embedded_class");
+        JavaEmbeddedClass sut = new JavaEmbeddedClass();
+
+        // `Hidden` is not on the classpath: Rust defines it from the bytes it registered
+        int answer = sut.hiddenAnswer();
+        if (answer != 42) {
+            throw new RuntimeException("expected: 42 got: " + answer);
+        }
+    }
+}
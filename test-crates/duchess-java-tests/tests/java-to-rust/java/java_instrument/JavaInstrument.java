@@ -0,0 +1,21 @@
+//@check-pass
+package java_instrument;
+
+public class JavaInstrument {
+    native int traced(int input);
+    native int tracedCallbacks();
+
+    public static void main(String[] args) {
+        System.loadLibrary("native_fn_instrument");
+        JavaInstrument sut = new JavaInstrument();
+        int before = sut.tracedCallbacks();
+
+        // Each call into a `#[java_function]` enters a `native_callback` span
+        sut.traced(1);
+        sut.traced(2);
+        int callbacks = sut.tracedCallbacks();
+        if (before != 0 || callbacks != 2) {
+            throw new RuntimeException("expected: 0 and 2 got: " + before + " and " + callbacks);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//@check-pass
+
+package java_rust_error_chain;
+
+public class JavaRustErrorChain {
+    native String raiseChain();
+    native String raiseJavaException();
+    native int raiseScalar();
+
+    public String javaFunction() {
+        throw new IllegalStateException("Exception from `javaFunction`");
+    }
+
+    public static void expectCauseChain(JavaRustErrorChain test) {
+        try {
+            test.raiseChain();
+        } catch (RuntimeException e) {
+            if (!e.getMessage().equals("failed to load config")) {
+                throw new RuntimeException("Wrong top-level message: " + e.getMessage());
+            }
+            Throwable cause = e.getCause();
+            if (cause == null || !cause.getMessage().equals("config.toml not found")) {
+                throw new RuntimeException("Wrong cause: " + cause);
+            }
+            if (cause.getCause() != null) {
+                throw new RuntimeException("Unexpected cause: " + cause.getCause());
+            }
+            return;
+        }
+
+        throw new RuntimeException("no exception thrown");
+    }
+
+    public static void expectOriginalJavaException(JavaRustErrorChain test) {
+        try {
+            test.raiseJavaException();
+        } catch (IllegalStateException e) {
+            if (!e.getMessage().equals("Exception from `javaFunction`")) {
+                throw new RuntimeException("Wrong message: " + e.getMessage());
+            }
+            return;
+        }
+
+        throw new RuntimeException("IllegalStateException not caught");
+    }
+
+    public static void expectScalarError(JavaRustErrorChain test) {
+        try {
+            test.raiseScalar();
+        } catch (RuntimeException e) {
+            if (!e.getMessage().equals("scalar failure")) {
+                throw new RuntimeException("Wrong message: " + e.getMessage());
+            }
+            return;
+        }
+
+        throw new RuntimeException("no exception thrown");
+    }
+
+    public static void main(String[] args) {
+        System.loadLibrary("java_rust_error_chain");
+        JavaRustErrorChain test = new JavaRustErrorChain();
+
+        expectCauseChain(test);
+        expectOriginalJavaException(test);
+        expectScalarError(test);
+    }
+}
@@ -0,0 +1,40 @@
+//@check-pass
+
+use duchess::prelude::*;
+
+duchess::java_package! {
+    package java_rust_error_chain;
+
+    public class java_rust_error_chain.JavaRustErrorChain { * }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to load config")]
+struct ConfigError {
+    #[source]
+    source: std::io::Error,
+}
+
+#[duchess::java_function(java_rust_error_chain.JavaRustErrorChain::raiseChain)]
+fn raise_chain(
+    _this: &java_rust_error_chain::JavaRustErrorChain,
+) -> Result<Java<java::lang::String>, Box<dyn std::error::Error + Send + Sync>> {
+    Err(ConfigError {
+        source: std::io::Error::new(std::io::ErrorKind::NotFound, "config.toml not found"),
+    }
+    .into())
+}
+
+#[duchess::java_function(java_rust_error_chain.JavaRustErrorChain::raiseJavaException)]
+fn raise_java_exception(
+    this: &java_rust_error_chain::JavaRustErrorChain,
+) -> Result<Java<java::lang::String>, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(this.java_function().assert_not_null().execute()?)
+}
+
+#[duchess::java_function(java_rust_error_chain.JavaRustErrorChain::raiseScalar)]
+fn raise_scalar(
+    _this: &java_rust_error_chain::JavaRustErrorChain,
+) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+    Err("scalar failure".into())
+}
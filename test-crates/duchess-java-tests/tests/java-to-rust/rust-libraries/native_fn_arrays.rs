@@ -23,7 +23,10 @@ fn combine_bytes(bytes: Option<&duchess::java::Array<i8>>) -> i64 {
 fn break_bytes(num: i64) -> duchess::Result<Java<java::Array<i8>>> {
     let unsigned_bytes = num.to_le_bytes();
     let signed_bytes = unsigned_bytes.iter().map(|x| *x as i8).collect::<Vec<i8>>();
-    let java_array: Java<java::Array<i8>> = signed_bytes.to_java().execute()?.unwrap();
+    let java_array: Java<java::Array<i8>> = signed_bytes
+        .to_java::<java::Array<i8>>()
+        .execute()?
+        .unwrap();
 
     return Ok(java_array);
 }
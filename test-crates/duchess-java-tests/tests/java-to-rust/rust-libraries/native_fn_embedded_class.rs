@@ -0,0 +1,51 @@
+//@check-pass
+use duchess::{prelude::*, DynValue, EmbeddedClass};
+
+duchess::java_package! {
+    package java_embedded_class;
+
+    public class JavaEmbeddedClass {
+        native int hiddenAnswer();
+    }
+}
+
+/// `javac --release 8 -g:none` of a class that is not on the classpath:
+///
+/// ```java
+/// package java_embedded_class;
+///
+/// public class Hidden {
+///     public static int answer() {
+///         return 42;
+///     }
+/// }
+/// ```
+const HIDDEN_CLASS: &[u8] = &[
+    0xca, 0xfe, 0xba, 0xbe, 0x00, 0x00, 0x00, 0x34, 0x00, 0x0c, 0x0a, 0x00, 0x02, 0x00, 0x03, 0x07,
+    0x00, 0x04, 0x0c, 0x00, 0x05, 0x00, 0x06, 0x01, 0x00, 0x10, 0x6a, 0x61, 0x76, 0x61, 0x2f, 0x6c,
+    0x61, 0x6e, 0x67, 0x2f, 0x4f, 0x62, 0x6a, 0x65, 0x63, 0x74, 0x01, 0x00, 0x06, 0x3c, 0x69, 0x6e,
+    0x69, 0x74, 0x3e, 0x01, 0x00, 0x03, 0x28, 0x29, 0x56, 0x07, 0x00, 0x08, 0x01, 0x00, 0x1a, 0x6a,
+    0x61, 0x76, 0x61, 0x5f, 0x65, 0x6d, 0x62, 0x65, 0x64, 0x64, 0x65, 0x64, 0x5f, 0x63, 0x6c, 0x61,
+    0x73, 0x73, 0x2f, 0x48, 0x69, 0x64, 0x64, 0x65, 0x6e, 0x01, 0x00, 0x04, 0x43, 0x6f, 0x64, 0x65,
+    0x01, 0x00, 0x06, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x01, 0x00, 0x03, 0x28, 0x29, 0x49, 0x00,
+    0x21, 0x00, 0x07, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x05, 0x00,
+    0x06, 0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0x11, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x05, 0x2a, 0xb7, 0x00, 0x01, 0xb1, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x0a, 0x00, 0x0b,
+    0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+    0x10, 0x2a, 0xac, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+#[duchess::java_function(java_embedded_class.JavaEmbeddedClass::hiddenAnswer)]
+fn hidden_answer(_this: &java_embedded_class::JavaEmbeddedClass) -> duchess::Result<i32> {
+    // The JVM was started by `java`, not by a `JvmBuilder`
+    duchess::register_embedded_classes(&[EmbeddedClass::new(
+        "java_embedded_class/Hidden",
+        HIDDEN_CLASS,
+    )]);
+
+    let hidden = duchess::DynClass::for_name("java_embedded_class.Hidden").execute()?;
+    match hidden.call_static("answer", &[]).execute()? {
+        DynValue::Int(answer) => Ok(answer),
+        other => panic!("expected an int, got {other:?}"),
+    }
+}
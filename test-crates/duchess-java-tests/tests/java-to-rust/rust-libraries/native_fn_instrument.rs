@@ -0,0 +1,84 @@
+//@check-pass
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex, Once,
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+duchess::java_package! {
+    package java_instrument;
+
+    public class JavaInstrument {
+        native int traced(int);
+        native int tracedCallbacks();
+    }
+}
+
+const TRACED_SPAN: &str =
+    "native_callback class=java_instrument/JavaInstrument method=traced descriptor=(I)I";
+
+static SPANS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Records the name and fields of every span created.
+struct SpanRecorder {
+    next_id: AtomicU64,
+}
+
+struct FieldsToString<'a>(&'a mut String);
+
+impl Visit for FieldsToString<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push_str(&format!(" {}={}", field.name(), value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let mut description = span.metadata().name().to_string();
+        span.record(&mut FieldsToString(&mut description));
+        SPANS.lock().unwrap().push(description);
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[duchess::java_function(java_instrument.JavaInstrument::traced)]
+fn traced(_this: &java_instrument::JavaInstrument, input: i32) -> duchess::Result<i32> {
+    Ok(input)
+}
+
+#[duchess::java_function(java_instrument.JavaInstrument::tracedCallbacks)]
+fn traced_callbacks(_this: &java_instrument::JavaInstrument) -> duchess::Result<i32> {
+    // The first call starts recording, so later calls count the spans since then
+    static START_RECORDING: Once = Once::new();
+    START_RECORDING.call_once(|| {
+        tracing::subscriber::set_global_default(SpanRecorder {
+            next_id: AtomicU64::new(0),
+        })
+        .unwrap();
+    });
+
+    let spans = SPANS.lock().unwrap();
+    Ok(spans.iter().filter(|span| *span == TRACED_SPAN).count() as i32)
+}
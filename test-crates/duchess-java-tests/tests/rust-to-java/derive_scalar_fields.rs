@@ -9,7 +9,7 @@ struct RustInstant {
 
 pub fn main() -> duchess::Result<()> {
     let rust = RustInstant { epoch_millis: 42 };
-    let java = rust.to_java().assert_not_null().execute()?;
+    let java: Java<java::time::Instant> = rust.to_java().assert_not_null().execute()?;
     let and_back = java.to_epoch_milli().execute()?;
     assert_eq!(rust.epoch_millis, and_back);
     Ok(())
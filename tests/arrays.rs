@@ -1,10 +1,17 @@
-use duchess::{java, Java, JvmOp, ToJava};
+use duchess::{
+    java,
+    java::{ArrayExt, ObjectArrayExt, PrimitiveArrayExt},
+    Error, Java, JvmOp, ToJava,
+};
 
 macro_rules! test_array {
     ($type: ty, $item: expr) => {
         for test_array in [vec![$item], vec![], vec![$item, $item, $item]] {
-            let java: Java<java::Array<$type>> =
-                test_array.to_java().assert_not_null().execute().unwrap();
+            let java: Java<java::Array<$type>> = test_array
+                .to_java::<java::Array<$type>>()
+                .assert_not_null()
+                .execute()
+                .unwrap();
             let and_back: Vec<_> = (&*java).execute().unwrap();
             assert_eq!(test_array, and_back);
         }
@@ -22,3 +29,134 @@ fn test_array_types() {
     test_array!(f32, 5_f32);
     test_array!(f64, 5_f64);
 }
+
+fn int_array(values: &[i32]) -> Java<java::Array<i32>> {
+    values
+        .to_java::<java::Array<i32>>()
+        .assert_not_null()
+        .execute()
+        .unwrap()
+}
+
+#[test]
+fn test_array_region() {
+    let array = int_array(&[1, 2, 3, 4, 5]);
+
+    let mut region = [0; 3];
+    array.get_region(1, &mut region).execute().unwrap();
+    assert_eq!(region, [2, 3, 4]);
+
+    array.set_region(3, &[40, 50]).execute().unwrap();
+    let and_back: Vec<i32> = (&*array).execute().unwrap();
+    assert_eq!(and_back, [1, 2, 3, 40, 50]);
+
+    let result = array.get_region(4, &mut [0; 2]).execute();
+    assert!(matches!(result, Err(Error::Thrown(_))));
+
+    let result = array.set_region(-1, &[0]).execute();
+    assert!(matches!(result, Err(Error::Thrown(_))));
+}
+
+#[test]
+fn test_array_critical() {
+    let array = int_array(&[1, 2, 3]);
+
+    let sum = array
+        .with_critical(|elements| elements.iter().sum::<i32>())
+        .execute::<i32>()
+        .unwrap();
+    assert_eq!(sum, 6);
+
+    array
+        .with_critical(|elements| elements.iter_mut().for_each(|e| *e *= 10))
+        .execute()
+        .unwrap();
+    let and_back: Vec<i32> = (&*array).execute().unwrap();
+    assert_eq!(and_back, [10, 20, 30]);
+
+    let len = int_array(&[])
+        .with_critical(|elements| elements.len() as i32)
+        .execute::<i32>()
+        .unwrap();
+    assert_eq!(len, 0);
+}
+
+#[test]
+fn test_array_access_inside_and_then() {
+    let array = int_array(&[1, 2, 3, 4]);
+
+    let sum: i32 = (&*array)
+        .and_then(|array, jvm| {
+            array.set_region(0, &[10]).execute_with(jvm)?;
+
+            let mut region = [0; 2];
+            array.get_region(2, &mut region).execute_with(jvm)?;
+            assert_eq!(region, [3, 4]);
+
+            array
+                .with_critical(|elements| elements.iter().sum::<i32>())
+                .execute_with(jvm)
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(sum, 10 + 2 + 3 + 4);
+}
+
+#[test]
+fn test_object_array_roundtrip() {
+    let strings = vec!["a".to_string(), "bc".to_string(), "".to_string()];
+    let java = strings
+        .to_java::<java::Array<java::lang::String>>()
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+    assert_eq!(java.length().execute().unwrap(), 3);
+
+    let and_back: Vec<String> = (&*java).execute().unwrap();
+    assert_eq!(strings, and_back);
+}
+
+#[test]
+fn test_object_array_get_set() {
+    let array: Java<java::Array<java::lang::String>> =
+        java::Array::<java::lang::String>::new(2).execute().unwrap();
+    assert_eq!(array.get(0).execute::<Option<String>>().unwrap(), None);
+
+    array.set(1, "hello".to_java()).execute().unwrap();
+    assert_eq!(
+        array.get(1).execute::<Option<String>>().unwrap().as_deref(),
+        Some("hello")
+    );
+
+    // the element op can be chained with the element's own methods
+    assert_eq!(array.get(1).length().execute().unwrap(), 5);
+
+    let nulls = (&*array).execute::<Vec<String>>();
+    assert!(matches!(nulls, Err(Error::NullDeref)));
+
+    let out_of_bounds = array.get(2).execute::<Option<String>>();
+    assert!(matches!(out_of_bounds, Err(Error::Thrown(_))));
+}
+
+#[test]
+fn test_multi_dimensional_array() {
+    let matrix = vec![vec![1, 2, 3], vec![], vec![4]];
+    let java = matrix
+        .to_java::<java::Array<java::Array<i32>>>()
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+
+    assert_eq!(java.get(0).length().execute().unwrap(), 3);
+
+    let and_back: Vec<Vec<i32>> = (&*java).execute().unwrap();
+    assert_eq!(matrix, and_back);
+}
+
+#[test]
+fn test_stack_trace_array() {
+    let exception = java::lang::Throwable::new().execute().unwrap();
+    let trace = exception.get_stack_trace().assert_not_null();
+    let frames: Vec<Java<java::lang::StackTraceElement>> = trace.clone().execute().unwrap();
+    assert_eq!(trace.length().execute().unwrap() as usize, frames.len());
+}
@@ -0,0 +1,64 @@
+use duchess::{java, prelude::*, AttachPolicy, Java, Jvm};
+
+fn current_thread() -> (String, bool) {
+    let thread: Java<java::lang::Thread> = java::lang::Thread::current_thread()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let name: String = thread.get_name().assert_not_null().execute().unwrap();
+    let daemon = thread.is_daemon().execute().unwrap();
+    (name, daemon)
+}
+
+#[test]
+fn threads_are_attached_with_their_rust_name() {
+    Jvm::builder()
+        .attach_policy(AttachPolicy::Daemon)
+        .try_launch()
+        .unwrap();
+
+    // Attached on first use, according to the policy
+    let (name, daemon) = std::thread::Builder::new()
+        .name("duchess-worker".to_string())
+        .spawn(current_thread)
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(name, "duchess-worker");
+    assert!(daemon);
+
+    // Attached before the closure runs
+    let (name, daemon) = Jvm::spawn("duchess-spawned", current_thread)
+        .unwrap()
+        .join()
+        .unwrap()
+        .unwrap();
+    assert_eq!(name, "duchess-spawned");
+    assert!(daemon);
+
+    // An explicit permanent attachment is not a daemon thread
+    let (name, daemon) = std::thread::Builder::new()
+        .name("duchess-permanent".to_string())
+        .spawn(|| {
+            Jvm::attach_thread_permanently().unwrap();
+            current_thread()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(name, "duchess-permanent");
+    assert!(!daemon);
+
+    // A thread's own policy overrides the builder's
+    let (name, daemon) = std::thread::Builder::new()
+        .name("duchess-overridden".to_string())
+        .spawn(|| {
+            AttachPolicy::Permanent.set_for_current_thread();
+            current_thread()
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(name, "duchess-overridden");
+    assert!(!daemon);
+}
@@ -0,0 +1,69 @@
+use duchess::{java, Error, Java, JvmOp, ToJava};
+use std::collections::HashMap;
+
+#[test]
+fn scalars_round_trip_through_boxes() {
+    macro_rules! round_trip {
+        ($($value:expr => $java:ident as $rust:ty,)*) => {
+            $(
+                let boxed: Java<java::lang::$java> =
+                    $value.to_java::<java::lang::$java>().execute().unwrap().unwrap();
+                let back: $rust = boxed.execute().unwrap();
+                assert_eq!(back, $value);
+            )*
+        };
+    }
+
+    round_trip! {
+        true => Boolean as bool,
+        'x' => Character as char,
+        0x41u16 => Character as u16,
+        -8i8 => Byte as i8,
+        -16i16 => Short as i16,
+        i32::MIN => Integer as i32,
+        i64::MAX => Long as i64,
+        1.5f32 => Float as f32,
+        -2.25f64 => Double as f64,
+    }
+}
+
+#[test]
+fn chars_outside_the_bmp_are_rejected() {
+    let result: Result<Option<Java<java::lang::Character>>, _> =
+        '🦀'.to_java::<java::lang::Character>().execute();
+    assert!(
+        matches!(result, Err(Error::CharOutOfRange(0x1F980))),
+        "{result:?}"
+    );
+
+    let surrogate: Java<java::lang::Character> = 0xD800u16
+        .to_java::<java::lang::Character>()
+        .execute()
+        .unwrap()
+        .unwrap();
+    let result: Result<char, _> = surrogate.execute();
+    assert!(
+        matches!(result, Err(Error::CharOutOfRange(0xD800))),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn collections_of_boxes() {
+    let list: Java<java::util::List<java::lang::Integer>> = vec![1, 2, 3]
+        .to_java::<java::util::List<java::lang::Integer>>()
+        .execute()
+        .unwrap()
+        .unwrap();
+    let second: Option<i32> = list.get(1).execute().unwrap();
+    assert_eq!(second, Some(2));
+
+    let map: Java<java::util::Map<java::lang::String, java::lang::Double>> =
+        HashMap::from([("pi".to_string(), 2.5f64)])
+            .to_java::<java::util::Map<java::lang::String, java::lang::Double>>()
+            .execute()
+            .unwrap()
+            .unwrap();
+    let pi: Option<f64> = map.get("pi").execute().unwrap();
+    assert_eq!(pi, Some(2.5));
+}
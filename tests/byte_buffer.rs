@@ -0,0 +1,71 @@
+use duchess::{
+    java,
+    java::{with_direct_byte_buffer, ByteBufferExt},
+    prelude::*,
+    Error, Java,
+};
+
+#[test]
+fn rust_memory_viewed_from_java() {
+    let mut frame = [1_u8, 2, 3, 4];
+
+    let sum = unsafe {
+        with_direct_byte_buffer(&mut frame, |jvm, buffer| {
+            assert!(buffer.is_direct().do_jni(jvm)?);
+            assert_eq!(buffer.capacity().do_jni(jvm)?, 4);
+            assert_eq!(buffer.get(2).do_jni(jvm)?, 3);
+
+            buffer.put(0, 42_i8).do_jni(jvm)?;
+            let mut sum = 0;
+            for index in 0..4 {
+                sum += buffer.get(index).do_jni(jvm)? as i32;
+            }
+            Ok(sum)
+        })
+    }
+    .unwrap();
+
+    assert_eq!(sum, 42 + 2 + 3 + 4);
+    assert_eq!(frame, [42, 2, 3, 4]);
+}
+
+#[test]
+fn java_direct_buffer_viewed_from_rust() {
+    let buffer = java::nio::ByteBuffer::allocate_direct(8)
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+
+    // SAFETY: no other code has access to the buffer
+    unsafe { buffer.with_direct_bytes_mut(|bytes| bytes.copy_from_slice(b"duchess!")) }.unwrap();
+    assert_eq!(buffer.get(7).execute().unwrap(), b'!' as i8);
+
+    let copy = unsafe { buffer.with_direct_bytes(|bytes| bytes.to_vec()) }.unwrap();
+    assert_eq!(copy, b"duchess!");
+
+    let read_only = buffer
+        .as_read_only_buffer()
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+    assert_eq!(
+        unsafe { read_only.with_direct_bytes(|bytes| bytes.len()) }.unwrap(),
+        8
+    );
+    assert!(matches!(
+        unsafe { read_only.with_direct_bytes_mut(|bytes| bytes.len()) },
+        Err(Error::UnviewableByteBuffer("read-only"))
+    ));
+}
+
+#[test]
+fn heap_buffer_is_not_direct() {
+    let buffer = java::nio::ByteBuffer::allocate(8)
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+    assert!(matches!(
+        unsafe { buffer.with_direct_bytes(|bytes| bytes.len()) },
+        Err(Error::UnviewableByteBuffer("non-direct"))
+    ));
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc};
+use duchess::{java, Error, Java, JvmOp, ToJava};
+
+#[test]
+fn date_times_round_trip() {
+    let time = Utc.timestamp_opt(-1, 999_999_999).unwrap();
+    let instant: Java<java::time::Instant> = time
+        .to_java::<java::time::Instant>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let text: String = instant.to_string().assert_not_null().execute().unwrap();
+    assert_eq!(text, "1969-12-31T23:59:59.999999999Z");
+    let back: DateTime<Utc> = instant.execute().unwrap();
+    assert_eq!(back, time);
+
+    let time: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-02-29T12:34:56.789+05:30").unwrap();
+    let zoned: Java<java::time::ZonedDateTime> = time
+        .to_java::<java::time::ZonedDateTime>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let text: String = zoned.to_string().assert_not_null().execute().unwrap();
+    assert_eq!(text, "2024-02-29T12:34:56.789+05:30");
+    let back: DateTime<FixedOffset> = zoned.execute().unwrap();
+    assert_eq!(back, time);
+    assert_eq!(back.offset(), time.offset());
+    let utc: DateTime<Utc> = zoned.execute().unwrap();
+    assert_eq!(utc, time);
+}
+
+#[test]
+fn local_dates_and_times_round_trip() {
+    let date = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+    let java: Java<java::time::LocalDate> = date
+        .to_java::<java::time::LocalDate>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let back: NaiveDate = java.execute().unwrap();
+    assert_eq!(back, date);
+
+    let time = date.and_hms_nano_opt(23, 59, 58, 1).unwrap();
+    let java: Java<java::time::LocalDateTime> = time
+        .to_java::<java::time::LocalDateTime>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let back: NaiveDateTime = java.execute().unwrap();
+    assert_eq!(back, time);
+}
+
+#[test]
+fn time_deltas_round_trip() {
+    for delta in [
+        TimeDelta::nanoseconds(-1),
+        TimeDelta::new(-5, 250).unwrap(),
+        TimeDelta::days(365),
+    ] {
+        let java: Java<java::time::Duration> = delta
+            .to_java::<java::time::Duration>()
+            .assert_not_null()
+            .execute()
+            .unwrap();
+        let back: TimeDelta = java.execute().unwrap();
+        assert_eq!(back, delta);
+    }
+}
+
+#[test]
+fn out_of_range_values_are_errors() {
+    let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap();
+    let result: Result<Option<Java<java::time::LocalDateTime>>, _> =
+        leap_second.to_java::<java::time::LocalDateTime>().execute();
+    assert!(
+        matches!(result, Err(Error::TimeOutOfRange { .. })),
+        "{result:?}"
+    );
+
+    let far_future: Java<java::time::LocalDate> = java::time::LocalDate::of(999_999, 1, 1)
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let result: Result<NaiveDate, _> = far_future.execute();
+    match result {
+        Err(Error::TimeOutOfRange { value, target }) => {
+            assert_eq!(value, "+999999-01-01");
+            assert_eq!(target, "chrono::NaiveDate");
+        }
+        _ => panic!("{result:?}"),
+    }
+
+    let forever: Java<java::time::Duration> = java::time::Duration::of_seconds(i64::MAX, 0i64)
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let result: Result<TimeDelta, _> = forever.execute();
+    assert!(
+        matches!(result, Err(Error::TimeOutOfRange { .. })),
+        "{result:?}"
+    );
+}
@@ -0,0 +1,97 @@
+use std::ffi::CStr;
+
+use duchess::{java, prelude::*, Error, Java, Jvm, Local};
+
+/// A minimal class file for `public class duchess.LoaderOnly`, with no members,
+/// which is only ever on the class path of the `URLClassLoader` in `custom_loader_finds_its_own_classes`.
+const LOADER_ONLY: &[u8] = b"\xca\xfe\xba\xbe\x00\x00\x00\x34\x00\x05\
+    \x01\x00\x12duchess/LoaderOnly\x07\x00\x01\
+    \x01\x00\x10java/lang/Object\x07\x00\x03\
+    \x00\x21\x00\x02\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00";
+
+/// Looks up a class by its JNI name, as generated `JavaObject::class` implementations do.
+#[derive(Clone)]
+struct FindClass(&'static CStr);
+
+impl JvmOp for FindClass {
+    type Output<'jvm> = Local<'jvm, java::lang::Class>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> duchess::LocalResult<'jvm, Self::Output<'jvm>> {
+        duchess::semver_unstable::find_class(jvm, self.0)
+    }
+}
+
+fn system_class_loader() -> Java<java::lang::ClassLoader> {
+    java::lang::ClassLoader::get_system_class_loader()
+        .assert_not_null()
+        .execute()
+        .unwrap()
+}
+
+#[test]
+fn with_class_loader_finds_classes() {
+    let loader = system_class_loader();
+
+    let class: Java<java::lang::Class> = FindClass(c"java/util/ArrayList")
+        .with_class_loader(&loader)
+        .execute()
+        .unwrap();
+    let name: String = class.get_name().assert_not_null().execute().unwrap();
+    assert_eq!(name, "java.util.ArrayList");
+
+    let size = java::util::ArrayList::<java::lang::String>::new()
+        .size()
+        .with_class_loader(&loader)
+        .execute()
+        .unwrap();
+    assert_eq!(size, 0);
+}
+
+#[test]
+fn missing_class_reports_original_error() {
+    let loader = system_class_loader();
+
+    let result = FindClass(c"duchess/DoesNotExist")
+        .with_class_loader(&loader)
+        .execute::<Java<java::lang::Class>>();
+    let Err(Error::Thrown(exception)) = result else {
+        panic!("expected an exception, got {result:?}");
+    };
+
+    let message: String = exception.to_string().assert_not_null().execute().unwrap();
+    assert!(message.contains("NoClassDefFoundError"), "{message}");
+}
+
+#[test]
+fn custom_loader_finds_its_own_classes() {
+    let dir = std::env::temp_dir().join(format!("duchess-class-loader-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("duchess")).unwrap();
+    std::fs::write(dir.join("duchess/LoaderOnly.class"), LOADER_ONLY).unwrap();
+
+    let uri: Java<java::net::URI> = java::io::File::new(dir.to_str().unwrap())
+        .to_uri()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let url: Java<java::net::URL> = uri.to_url().assert_not_null().execute().unwrap();
+    let urls: Java<java::Array<java::net::URL>> = vec![url]
+        .to_java::<java::Array<java::net::URL>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let loader: Java<java::net::URLClassLoader> =
+        java::net::URLClassLoader::new(&urls).execute().unwrap();
+
+    let result = FindClass(c"duchess/LoaderOnly").execute::<Java<java::lang::Class>>();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+
+    let class: Java<java::lang::Class> = FindClass(c"duchess/LoaderOnly")
+        .with_class_loader(&loader)
+        .execute()
+        .unwrap();
+    let name: String = class.get_name().assert_not_null().execute().unwrap();
+    assert_eq!(name, "duchess.LoaderOnly");
+
+    loader.close().execute().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
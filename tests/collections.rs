@@ -0,0 +1,132 @@
+use duchess::{java, Error, Java, JvmOp, ToJava};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+#[test]
+fn lists_round_trip() {
+    let numbers: Vec<i32> = (0..1000).collect();
+    let list: Java<java::util::List<java::lang::Integer>> = numbers
+        .to_java::<java::util::List<java::lang::Integer>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+
+    let back: Vec<i32> = list.execute().unwrap();
+    assert_eq!(back, numbers);
+    let back: VecDeque<i32> = list.execute().unwrap();
+    assert_eq!(back, numbers);
+
+    let deque: VecDeque<String> = ["a", "b"].into_iter().map(String::from).collect();
+    let list: Java<java::util::ArrayList<java::lang::String>> = deque
+        .to_java::<java::util::ArrayList<java::lang::String>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let back: Vec<String> = list.execute().unwrap();
+    assert_eq!(back, ["a", "b"]);
+}
+
+#[test]
+fn sets_round_trip() {
+    let strings: HashSet<String> = ["x", "y", "z"].into_iter().map(String::from).collect();
+    let set: Java<java::util::Set<java::lang::String>> = strings
+        .to_java::<java::util::Set<java::lang::String>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert_eq!(set.size().execute().unwrap(), 3);
+
+    let back: BTreeSet<String> = set.execute().unwrap();
+    assert_eq!(
+        back,
+        ["x", "y", "z"].into_iter().map(String::from).collect()
+    );
+    let back: HashSet<String> = set.execute().unwrap();
+    assert_eq!(back, strings);
+
+    let ordered: BTreeSet<i64> = [3, 1, 2].into();
+    let set: Java<java::util::LinkedHashSet<java::lang::Long>> = ordered
+        .to_java::<java::util::LinkedHashSet<java::lang::Long>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let back: BTreeSet<i64> = set.execute().unwrap();
+    assert_eq!(back, ordered);
+}
+
+#[test]
+fn maps_round_trip() {
+    let scores: BTreeMap<String, f64> = [("a".to_string(), 1.5), ("b".to_string(), -2.0)].into();
+    let map: Java<java::util::Map<java::lang::String, java::lang::Double>> = scores
+        .to_java::<java::util::Map<java::lang::String, java::lang::Double>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+
+    let back: BTreeMap<String, f64> = map.execute().unwrap();
+    assert_eq!(back, scores);
+    let back: HashMap<String, f64> = map.execute().unwrap();
+    assert_eq!(back, scores.clone().into_iter().collect());
+}
+
+#[test]
+fn ordered_collections_keep_their_order() {
+    let words: BTreeSet<String> = (0..100).map(|i| format!("word{i:03}")).collect();
+    let set: Java<java::util::Set<java::lang::String>> = words
+        .to_java::<java::util::Set<java::lang::String>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let in_order: Vec<String> = set.execute().unwrap();
+    assert_eq!(in_order, words.iter().cloned().collect::<Vec<_>>());
+
+    let lengths: BTreeMap<String, i32> =
+        words.iter().map(|w| (w.clone(), w.len() as i32)).collect();
+    let map: Java<java::util::Map<java::lang::String, java::lang::Integer>> = lengths
+        .to_java::<java::util::Map<java::lang::String, java::lang::Integer>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let keys: Vec<String> = map.key_set().assert_not_null().execute().unwrap();
+    assert_eq!(keys, lengths.keys().cloned().collect::<Vec<_>>());
+}
+
+#[test]
+fn any_collection_subtype_converts() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    for s in ["b", "a", "b"] {
+        list.add(s).execute().unwrap();
+    }
+
+    let unique: BTreeSet<String> = list.execute().unwrap();
+    assert_eq!(unique, ["a", "b"].into_iter().map(String::from).collect());
+
+    let collection: Java<java::util::Collection<java::lang::String>> = list.upcast();
+    let all: Vec<String> = collection.execute().unwrap();
+    assert_eq!(all, ["b", "a", "b"]);
+}
+
+#[test]
+fn null_elements_are_errors() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    list.add("a").execute().unwrap();
+    list.add(&None::<Java<java::lang::String>>)
+        .execute()
+        .unwrap();
+
+    let result: Result<Vec<String>, _> = list.execute();
+    assert!(matches!(result, Err(Error::NullDeref)), "{result:?}");
+}
+
+#[test]
+fn null_map_values_are_errors() {
+    let map: Java<java::util::HashMap<java::lang::String, java::lang::String>> =
+        java::util::HashMap::new().execute().unwrap();
+    map.put("a", &None::<Java<java::lang::String>>)
+        .execute::<Option<Java<java::lang::String>>>()
+        .unwrap();
+
+    let result: Result<HashMap<String, String>, _> = map.execute();
+    assert!(matches!(result, Err(Error::NullDeref)), "{result:?}");
+}
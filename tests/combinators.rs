@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use duchess::{java, prelude::*, Error, Java};
+
+fn list_of(values: &[&str]) -> Java<java::util::ArrayList<java::lang::String>> {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    for value in values {
+        list.add(*value).execute().unwrap();
+    }
+    list
+}
+
+#[test]
+fn map_and_then_and_inspect() {
+    let list = list_of(&["a", "bb", "ccc"]);
+
+    let doubled: i32 = list.size().map(|size, _jvm| size * 2).execute().unwrap();
+    assert_eq!(doubled, 6);
+
+    let last: String = list
+        .size()
+        .and_then(|size, jvm| {
+            list.get(size - 1)
+                .assert_not_null()
+                .execute_with::<String>(jvm)
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(last, "ccc");
+
+    static SEEN: AtomicI32 = AtomicI32::new(0);
+    let size: i32 = list
+        .size()
+        .inspect(|size, _jvm| SEEN.store(*size, Ordering::SeqCst))
+        .execute()
+        .unwrap();
+    assert_eq!(size, 3);
+    assert_eq!(SEEN.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn and_then_propagates_exceptions() {
+    let list = list_of(&[]);
+    let result: Result<String, _> = list
+        .size()
+        .and_then(|size, jvm| list.get(size).assert_not_null().execute_with::<String>(jvm))
+        .execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
+
+#[test]
+fn join_and_sequence() {
+    let list = list_of(&["a", "bb"]);
+
+    let (size, first): (i32, String) = duchess::join(list.size(), list.get(0).assert_not_null())
+        .execute()
+        .unwrap();
+    assert_eq!((size, first.as_str()), (2, "a"));
+
+    let (empty, first, second): (bool, String, Option<String>) =
+        (list.is_empty(), list.get(0).assert_not_null(), list.get(1))
+            .execute()
+            .unwrap();
+    assert_eq!(
+        (empty, first.as_str(), second.as_deref()),
+        (false, "a", Some("bb"))
+    );
+
+    let all: Vec<String> = (0..2)
+        .map(|index| list.get(index).assert_not_null())
+        .collect::<Vec<_>>()
+        .execute()
+        .unwrap();
+    assert_eq!(all, ["a", "bb"]);
+
+    // The first exception stops the sequence
+    let result: Result<Vec<String>, _> = (0..3)
+        .map(|index| list.get(index).assert_not_null())
+        .collect::<Vec<_>>()
+        .execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
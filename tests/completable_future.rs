@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use duchess::{java, prelude::*, Error, Identity, Java};
+
+#[tokio::test]
+async fn await_completable_future_completed_from_java() {
+    let future: Java<java::util::concurrent::CompletableFuture<java::lang::String>> =
+        java::util::concurrent::CompletableFuture::new()
+            .execute()
+            .unwrap();
+
+    let completer = std::thread::spawn({
+        let future: Java<java::util::concurrent::CompletableFuture<java::lang::String>> =
+            future.execute().unwrap();
+        move || {
+            std::thread::sleep(Duration::from_millis(50));
+            future.complete("hello").execute()
+        }
+    });
+
+    let value = (&future).await.unwrap().unwrap();
+    let value: String = value.execute().unwrap();
+    assert_eq!(value, "hello");
+    assert!(completer.join().unwrap().unwrap());
+
+    // A future that has already completed resolves immediately
+    let value: String = future.await.unwrap().unwrap().execute().unwrap();
+    assert_eq!(value, "hello");
+}
+
+#[tokio::test]
+async fn await_completable_future_completed_exceptionally() {
+    let future: Java<java::util::concurrent::CompletableFuture<java::lang::Object>> =
+        java::util::concurrent::CompletableFuture::new()
+            .execute()
+            .unwrap();
+    let exception: Java<java::lang::Throwable> = java::lang::RuntimeException::new()
+        .execute::<Java<java::lang::RuntimeException>>()
+        .unwrap()
+        .upcast();
+    future.complete_exceptionally(&exception).execute().unwrap();
+
+    match future.await {
+        Err(Error::Thrown(thrown)) => assert!(Identity(&thrown) == Identity(&exception)),
+        result => panic!("expected an exception, got {result:?}"),
+    }
+}
+
+#[tokio::test]
+async fn rust_future_completes_java_future() {
+    let (java_future, complete) = duchess::to_completable_future::<java::lang::String, _>(async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        Ok::<_, Error<Java<java::lang::Throwable>>>("from rust".to_string())
+    })
+    .unwrap();
+    assert!(!java_future.is_done().execute().unwrap());
+
+    tokio::spawn(complete).await.unwrap().unwrap();
+    let value: String = java_future.join().assert_not_null().execute().unwrap();
+    assert_eq!(value, "from rust");
+}
+
+#[tokio::test]
+async fn rust_error_completes_java_future_exceptionally() {
+    let (java_future, complete) = duchess::to_completable_future::<java::lang::String, _>(async {
+        Err::<String, Box<dyn std::error::Error + Send + Sync>>("no greeting".into())
+    })
+    .unwrap();
+
+    complete.await.unwrap();
+    assert!(java_future.is_completed_exceptionally().execute().unwrap());
+    assert!(matches!(
+        java_future.join().execute::<Option<String>>(),
+        Err(Error::Thrown(_))
+    ));
+}
+
+#[tokio::test]
+async fn dropping_rust_future_cancels_java_future() {
+    let (java_future, complete) =
+        duchess::to_completable_future::<java::lang::String, _>(std::future::pending::<
+            Result<String, Error<Java<java::lang::Throwable>>>,
+        >())
+        .unwrap();
+
+    drop(complete);
+    assert!(java_future.is_cancelled().execute().unwrap());
+
+    // Awaiting the cancelled future from Rust runs (and frees) its completion callback
+    match java_future.await {
+        Err(Error::Thrown(thrown)) => {
+            let text: String = thrown.to_string().assert_not_null().execute().unwrap();
+            assert!(
+                text.starts_with("java.util.concurrent.CancellationException"),
+                "{text}"
+            );
+        }
+        result => panic!("expected a cancellation, got {result:?}"),
+    }
+}
@@ -0,0 +1,147 @@
+use duchess::{java, prelude::*, DynClass, DynObject, DynValue, Error, Java};
+
+#[test]
+fn construct_and_call_methods() {
+    let class: DynClass = DynClass::for_name("java.util.ArrayList").execute().unwrap();
+    assert_eq!(class.name(), "java.util.ArrayList");
+
+    let list: DynObject = class.new_instance(&[]).execute().unwrap();
+    let added: bool = list.call("add", &["hello".into()]).execute().unwrap();
+    assert!(added);
+    list.call("add", &[DynValue::Null])
+        .execute::<DynValue>()
+        .unwrap();
+
+    let size: i32 = list.call("size", &[]).execute().unwrap();
+    assert_eq!(size, 2);
+
+    let first: String = list.call("get", &[0i32.into()]).execute().unwrap();
+    assert_eq!(first, "hello");
+    let second: Option<String> = list.call("get", &[1i32.into()]).execute().unwrap();
+    assert_eq!(second, None);
+
+    let list_class: DynClass = list.class().execute().unwrap();
+    assert_eq!(list_class.name(), "java.util.ArrayList");
+}
+
+#[test]
+fn overloads_are_chosen_from_arguments() {
+    let math: DynClass = DynClass::for_name("java/lang/Math").execute().unwrap();
+
+    let max = math
+        .call_static("max", &[1i32.into(), 2i32.into()])
+        .execute::<DynValue>()
+        .unwrap();
+    assert!(matches!(max, DynValue::Int(2)), "{max:?}");
+
+    // `int` widens to `long`, so `max(long, long)` is the only match
+    let max = math
+        .call_static("max", &[1i32.into(), 2i64.into()])
+        .execute::<DynValue>()
+        .unwrap();
+    assert!(matches!(max, DynValue::Long(2)), "{max:?}");
+
+    let result: Result<DynValue, _> = math
+        .call_static("max", &["one".into(), 2i32.into()])
+        .execute();
+    assert!(
+        matches!(result, Err(Error::NoMatchingMember { .. })),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn objects_from_typed_code_can_be_passed() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    list.add("a").execute().unwrap();
+
+    let collections: DynClass = DynClass::for_name("java.util.Collections")
+        .execute()
+        .unwrap();
+    let max: String = collections
+        .call_static("max", &[list.into()])
+        .execute()
+        .unwrap();
+    assert_eq!(max, "a");
+}
+
+#[test]
+fn static_and_instance_fields() {
+    let integer: DynClass = DynClass::for_name("java.lang.Integer").execute().unwrap();
+    let max: i32 = integer.get_static_field("MAX_VALUE").execute().unwrap();
+    assert_eq!(max, i32::MAX);
+
+    let point_class: DynClass = DynClass::for_name("java.awt.Point").execute().unwrap();
+    let point: DynObject = point_class
+        .new_instance(&[3i32.into(), 4i32.into()])
+        .execute()
+        .unwrap();
+    let x: i32 = point.get_field("x").execute().unwrap();
+    assert_eq!(x, 3);
+
+    point.set_field("y", 10i16).execute().unwrap();
+    let y: i32 = point.get_field("y").execute().unwrap();
+    assert_eq!(y, 10);
+
+    let result: Result<(), _> = point.set_field("y", "ten").execute();
+    assert!(
+        matches!(result, Err(Error::NoMatchingMember { .. })),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn errors() {
+    let list: DynObject = DynClass::for_name("java.util.ArrayList")
+        .execute::<DynClass>()
+        .unwrap()
+        .new_instance(&[])
+        .execute()
+        .unwrap();
+
+    let result: Result<DynValue, _> = list.call("frobnicate", &[]).execute();
+    assert!(
+        matches!(result, Err(Error::NoMatchingMember { .. })),
+        "{result:?}"
+    );
+
+    let result: Result<DynValue, _> = list.call("get", &[0i32.into()]).execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+
+    let result: Result<String, _> = list.call("size", &[]).execute();
+    assert!(
+        matches!(result, Err(Error::DynTypeMismatch { .. })),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn classes_can_be_looked_up_inside_operations() {
+    let max: i32 = java::lang::Thread::current_thread()
+        .and_then(|_thread, jvm| {
+            let math = jvm.dyn_class("java.lang.Math")?;
+            math.call_static("max", &[3i32.into(), 7i32.into()])
+                .execute_with::<i32>(jvm)
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(max, 7);
+}
+
+#[test]
+fn classes_are_looked_up_again_once_dropped() {
+    let class: DynClass = DynClass::for_name("java.util.HashMap").execute().unwrap();
+    let map: DynObject = class.new_instance(&[]).execute().unwrap();
+    drop(class);
+
+    // The object keeps its class alive
+    let size: i32 = map.call("size", &[]).execute().unwrap();
+    assert_eq!(size, 0);
+    drop(map);
+
+    let class: DynClass = DynClass::for_name("java.util.HashMap").execute().unwrap();
+    let map: DynObject = class.new_instance(&[]).execute().unwrap();
+    let empty: bool = map.call("isEmpty", &[]).execute().unwrap();
+    assert!(empty);
+}
@@ -0,0 +1,45 @@
+use std::ffi::CStr;
+
+use duchess::{java, prelude::*, EmbeddedClass, Identity, Java, Jvm, Local};
+
+/// A minimal class file for `public class duchess.EmbeddedTest`, with no members.
+const EMBEDDED_TEST: EmbeddedClass = EmbeddedClass::new(
+    "duchess/EmbeddedTest",
+    b"\xca\xfe\xba\xbe\x00\x00\x00\x34\x00\x05\
+      \x01\x00\x14duchess/EmbeddedTest\x07\x00\x01\
+      \x01\x00\x10java/lang/Object\x07\x00\x03\
+      \x00\x21\x00\x02\x00\x04\x00\x00\x00\x00\x00\x00\x00\x00",
+);
+
+/// Looks up a class by its JNI name, as generated `JavaObject::class` implementations do.
+#[derive(Clone)]
+struct FindClass(&'static CStr);
+
+impl JvmOp for FindClass {
+    type Output<'jvm> = Local<'jvm, java::lang::Class>;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> duchess::LocalResult<'jvm, Self::Output<'jvm>> {
+        duchess::semver_unstable::find_class(jvm, self.0)
+    }
+}
+
+#[test]
+fn embedded_class_is_defined_on_first_use() {
+    // This crate implements no Java interfaces, so duchess-build-rs only embedded duchess's own classes
+    let generated: &[EmbeddedClass] = duchess::embedded_classes!();
+    let names: Vec<&str> = generated.iter().map(|class| class.jni_name()).collect();
+    assert_eq!(names, ["duchess/CompletionCallback"]);
+
+    Jvm::builder()
+        .embed_classes(&[EMBEDDED_TEST])
+        .try_launch()
+        .unwrap();
+
+    let class: Java<java::lang::Class> = FindClass(c"duchess/EmbeddedTest").execute().unwrap();
+    let name: String = class.get_name().assert_not_null().execute().unwrap();
+    assert_eq!(name, "duchess.EmbeddedTest");
+
+    // The class is only defined once
+    let again: Java<java::lang::Class> = FindClass(c"duchess/EmbeddedTest").execute().unwrap();
+    assert!(Identity(&class) == Identity(&again));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "tokio")]
+
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use duchess::{java, prelude::*, Java, Jvm};
+
+static CANCELLED_RAN: AtomicBool = AtomicBool::new(false);
+
+#[tokio::test(flavor = "multi_thread")]
+async fn execute_async_runs_on_attached_pool() {
+    Jvm::builder().async_pool_size(2).try_launch().unwrap();
+
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute_async().await.unwrap();
+    let size = Jvm::run_async(move || {
+        list.add("hello").execute()?;
+        list.size().execute()
+    })
+    .await
+    .unwrap();
+    assert_eq!(size, 1);
+
+    // Operations run on the pool, not on the runtime's threads
+    let name: String = Jvm::run_async(|| {
+        let thread: Java<java::lang::Thread> = java::lang::Thread::current_thread()
+            .assert_not_null()
+            .execute()?;
+        thread.get_name().assert_not_null().execute()
+    })
+    .await
+    .unwrap();
+    assert!(name.starts_with("duchess-async-"), "{name}");
+
+    // Keep both pool threads busy, so the next operation is still queued when its future is dropped
+    let busy: Vec<_> = (0..2)
+        .map(|_| {
+            Jvm::run_async(|| {
+                std::thread::sleep(Duration::from_millis(200));
+                Ok(())
+            })
+        })
+        .collect();
+    let cancelled = Jvm::run_async(|| {
+        CANCELLED_RAN.store(true, Ordering::SeqCst);
+        Ok(())
+    });
+    drop(cancelled);
+
+    for busy in busy {
+        busy.await.unwrap();
+    }
+    let _: Java<java::lang::Object> = java::lang::Object::new().execute_async().await.unwrap();
+    assert!(!CANCELLED_RAN.load(Ordering::SeqCst));
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use duchess::{java, prelude::*, Identity, Java, Semantic};
+
+fn string(s: &str) -> Java<java::lang::String> {
+    s.to_java::<java::lang::String>()
+        .assert_not_null()
+        .execute()
+        .unwrap()
+}
+
+fn copy<T: duchess::JavaObject>(r: &Java<T>) -> Java<T> {
+    r.execute().unwrap()
+}
+
+#[test]
+fn identity_compares_references() {
+    let a = string("hello");
+    let b = string("hello");
+
+    assert!(Identity(&a) == Identity(&copy(&a)));
+    assert!(Identity(&a) != Identity(&b));
+
+    let mut map = HashMap::new();
+    map.insert(Identity(copy(&a)), 1);
+    map.insert(Identity(copy(&b)), 2);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&Identity(copy(&a))], 1);
+}
+
+#[test]
+fn semantic_uses_java_methods() {
+    let a = string("hello");
+    let b = string("hello");
+    let c = string("world");
+
+    assert_eq!(Semantic(&a), Semantic(&b));
+    assert_ne!(Semantic(&a), Semantic(&c));
+
+    let mut map = HashMap::new();
+    map.insert(Semantic(copy(&a)), 1);
+    map.insert(Semantic(copy(&b)), 2);
+    map.insert(Semantic(copy(&c)), 3);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&Semantic(a)], 2);
+
+    assert_eq!(Semantic(&c).to_string(), "world");
+    assert_eq!(format!("{:?}", Semantic(c)), "world");
+}
+
+#[test]
+fn semantic_formatting_describes_failures() {
+    let null: Option<Java<java::lang::String>> = None;
+    assert_eq!(
+        Semantic(&null).to_string(),
+        format!(
+            "<failed to call toString: {}>",
+            duchess::Error::<Java<java::lang::Throwable>>::NullDeref
+        )
+    );
+}
+
+#[test]
+fn locals_compare_with_the_operation_jvm() {
+    let b = string("hello");
+
+    "hello"
+        .to_java::<java::lang::String>()
+        .assert_not_null()
+        .and_then(|local_a, jvm| {
+            let local_b = jvm.local(&*b);
+            assert!(!Identity(&local_a).identity_eq(&Identity(&local_b), jvm)?);
+            assert!(Semantic(&local_a).semantic_eq(&Semantic(&local_b), jvm)?);
+            assert_eq!(
+                Semantic(&local_a).semantic_hash(jvm)?,
+                Semantic(&local_b).semantic_hash(jvm)?
+            );
+            Ok(())
+        })
+        .execute::<()>()
+        .unwrap();
+}
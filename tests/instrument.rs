@@ -0,0 +1,104 @@
+#![cfg(feature = "instrument")]
+
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use duchess::{java, prelude::*, Java, Jvm};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Records the name and fields of every span created.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+struct FieldsToString<'a>(&'a mut String);
+
+impl Visit for FieldsToString<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push_str(&format!(" {}={}", field.name(), value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let mut description = span.metadata().name().to_string();
+        span.record(&mut FieldsToString(&mut description));
+        self.spans.lock().unwrap().push(description);
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[test]
+fn java_calls_are_traced_and_counted() {
+    let recorder = SpanRecorder::default();
+    let before = Jvm::metrics();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let list: Java<java::util::ArrayList<java::lang::String>> =
+            java::util::ArrayList::new().execute().unwrap();
+        list.add("hello").execute().unwrap();
+        assert!(list.get(1).execute::<Option<String>>().is_err());
+        let epoch: Option<Java<java::time::Instant>> =
+            java::time::Instant::get_epoch().execute().unwrap();
+        assert!(epoch.is_some());
+
+        // A thread that has never used the JVM is attached by its first operation
+        std::thread::spawn(|| {
+            let _: Java<java::util::ArrayList<java::lang::String>> =
+                java::util::ArrayList::new().execute().unwrap();
+        })
+        .join()
+        .unwrap();
+    });
+
+    let spans = recorder.spans.lock().unwrap();
+    assert!(
+        spans.contains(
+            &"java_call kind=constructor class=java/util/ArrayList member=<init> descriptor=()V"
+                .to_string()
+        ),
+        "{spans:#?}"
+    );
+    assert!(
+        spans.contains(&"java_call kind=method class=java/util/ArrayList member=add descriptor=(Ljava/lang/Object;)Z".to_string()),
+        "{spans:#?}"
+    );
+    assert!(
+        spans.contains(&"java_call kind=field class=java/time/Instant member=EPOCH descriptor=Ljava/time/Instant;".to_string()),
+        "{spans:#?}"
+    );
+
+    let after = Jvm::metrics();
+    assert!(after.attaches > before.attaches);
+    assert!(after.global_refs > before.global_refs);
+    assert!(after.local_refs > before.local_refs);
+    assert!(after.exceptions > before.exceptions);
+}
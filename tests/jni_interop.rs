@@ -0,0 +1,87 @@
+#![cfg(feature = "jni-interop")]
+
+use duchess::{java, prelude::*, Error, Java, Jvm};
+use jni::objects::{GlobalRef, JObject, JValue};
+
+fn list_of(items: &[&str]) -> Java<java::util::ArrayList<java::lang::String>> {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    for item in items {
+        list.add(*item).execute().unwrap();
+    }
+    list
+}
+
+#[test]
+fn with_jni_env_runs_jni_code() {
+    let list = list_of(&["a", "b"]);
+
+    let size: i32 = (&list)
+        .and_then(|list, jvm| {
+            let list = JObject::from(jvm.local(&**list));
+            jvm.with_jni_env(|env| env.call_method(&list, "size", "()I", &[])?.i())
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(size, 2);
+
+    let result: Result<(), _> = (&list)
+        .and_then(|list, jvm| {
+            let list = JObject::from(jvm.local(&**list));
+            jvm.with_jni_env(|env| {
+                env.call_method(&list, "get", "(I)Ljava/lang/Object;", &[JValue::Int(5)])?;
+                Ok(())
+            })
+        })
+        .execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
+
+#[test]
+fn jni_references_can_be_executed() {
+    let list = list_of(&["a", "b"]);
+
+    let local_round_trip: String = (&list)
+        .and_then(|list, jvm| {
+            let list = JObject::from(jvm.local(&**list));
+            java::lang::Object::to_string(&list)
+                .assert_not_null()
+                .execute_with::<String>(jvm)
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(local_round_trip, "[a, b]");
+
+    let global: GlobalRef = (&list)
+        .and_then(|list, jvm| jvm.jni_global_ref(&**list))
+        .execute()
+        .unwrap();
+    let back: String = java::lang::Object::to_string(&global)
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert_eq!(back, "[a, b]");
+
+    let null: Option<Java<java::lang::Object>> = (&JObject::null()).execute().unwrap();
+    assert!(null.is_none());
+}
+
+#[test]
+fn from_jni_env_runs_duchess_code() {
+    let list = list_of(&["a", "b", "c"]);
+
+    let size: i32 = (&list)
+        .and_then(|_, jvm| {
+            jvm.with_jni_env(|env| {
+                // e.g. a native function written with `jni` that calls into duchess
+                Ok(Jvm::from_jni_env(env, |jvm| {
+                    let size: i32 = list.size().execute_with(jvm)?;
+                    let again: i32 = list.size().execute().expect("nested execute");
+                    Ok(size + again)
+                }))
+            })?
+        })
+        .execute()
+        .unwrap();
+    assert_eq!(size, 6);
+}
@@ -0,0 +1,68 @@
+use duchess::{java, prelude::*, Error, GarbageCollector, Jvm};
+
+fn property(key: &str) -> Option<String> {
+    java::lang::System::get_property(key).execute().unwrap()
+}
+
+#[test]
+fn typed_options() {
+    // Malformed options are reported before trying to create the JVM
+    let result = Jvm::builder().max_heap_size(0).try_launch();
+    assert!(
+        matches!(&result, Err(Error::InvalidJvmOption { option, .. }) if option == "-Xmx0"),
+        "{result:?}"
+    );
+
+    let result = Jvm::builder().system_property("", "value").try_launch();
+    assert!(
+        matches!(result, Err(Error::InvalidJvmOption { .. })),
+        "{result:?}"
+    );
+
+    let result = Jvm::builder()
+        .add_opens("java.base", "", "ALL-UNNAMED")
+        .try_launch();
+    assert!(
+        matches!(&result, Err(Error::InvalidJvmOption { option, .. }) if option == "--add-opens=java.base/=ALL-UNNAMED"),
+        "{result:?}"
+    );
+
+    let result = Jvm::builder().custom("Xmx64m").try_launch();
+    assert!(
+        matches!(&result, Err(Error::InvalidJvmOption { option, .. }) if option == "Xmx64m"),
+        "{result:?}"
+    );
+
+    std::env::set_var("DUCHESS_JVM_OPTS", "-Dduchess.test.env=\"unterminated");
+    let result = Jvm::builder().try_launch();
+    assert!(
+        matches!(&result, Err(Error::InvalidJvmOption { option, .. }) if option == "-Dduchess.test.env=unterminated"),
+        "{result:?}"
+    );
+
+    std::env::set_var(
+        "DUCHESS_JVM_OPTS",
+        r#" -Dduchess.test.env=yes  -Dduchess.test.both=env "-Dduchess.test.path=C:\Program Files\x" -Dduchess.test.quoted='a "b"' "#,
+    );
+    Jvm::builder()
+        .initial_heap_size(16 * 1024 * 1024)
+        .max_heap_size(256 * 1024 * 1024)
+        .thread_stack_size(1024 * 1024)
+        .system_property("duchess.test.typed", "yes")
+        .system_property("duchess.test.both", "builder")
+        .add_opens("java.base", "java.lang", "ALL-UNNAMED")
+        .add_exports("java.base", "sun.nio.ch", "ALL-UNNAMED")
+        .garbage_collector(GarbageCollector::Serial)
+        .check_jni(true)
+        .try_launch()
+        .unwrap();
+
+    assert_eq!(property("duchess.test.typed").as_deref(), Some("yes"));
+    assert_eq!(property("duchess.test.env").as_deref(), Some("yes"));
+    assert_eq!(property("duchess.test.both").as_deref(), Some("env"));
+    assert_eq!(
+        property("duchess.test.path").as_deref(),
+        Some(r"C:\Program Files\x")
+    );
+    assert_eq!(property("duchess.test.quoted").as_deref(), Some(r#"a "b""#));
+}
@@ -0,0 +1,34 @@
+use duchess::{Error, Jvm};
+
+#[test]
+fn unrecognized_option_is_reported() {
+    let result = Jvm::builder()
+        .custom("-XX:+DuchessNoSuchOption")
+        .try_launch();
+    let Err(error) = result else {
+        panic!("expected the JVM to reject its options, got {result:?}");
+    };
+    let message = error.to_string();
+    match error {
+        Error::JvmCreationFailed {
+            options,
+            unchecked_options,
+            ..
+        } => {
+            assert!(options.contains(&"-XX:+DuchessNoSuchOption".to_string()));
+            assert_eq!(unchecked_options, ["-XX:+DuchessNoSuchOption"]);
+            assert!(
+                message.contains("check the option `-XX:+DuchessNoSuchOption`"),
+                "{message}"
+            );
+        }
+        error => panic!("expected the JVM to reject its options, got {error:?}"),
+    }
+
+    // The JVM can ignore the option instead
+    Jvm::builder()
+        .custom("-XX:+DuchessNoSuchOption")
+        .ignore_unrecognized(true)
+        .try_launch()
+        .unwrap();
+}
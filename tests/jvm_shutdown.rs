@@ -0,0 +1,71 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc,
+};
+
+use duchess::{java, prelude::*, Error, JavaObject, Jvm};
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+static SHUT_DOWN: AtomicBool = AtomicBool::new(false);
+
+#[test]
+fn shutdown_waits_for_running_operations_and_runs_hooks() {
+    let (release_tx, release_rx) = mpsc::channel();
+    Jvm::builder()
+        .on_jvm_start(|jvm| {
+            java::util::ArrayList::<java::lang::Object>::class(jvm)?;
+            STARTED.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .on_jvm_shutdown(move |jvm| {
+            // The JVM is still usable from shutdown hooks
+            java::lang::Object::new().do_jni(jvm)?;
+            SHUT_DOWN.store(true, Ordering::SeqCst);
+            release_tx.send(()).unwrap();
+            Ok(())
+        })
+        .try_launch()
+        .unwrap();
+    assert!(STARTED.load(Ordering::SeqCst));
+
+    let lock: Java<java::lang::Object> = java::lang::Object::new().execute().unwrap();
+    let (started_tx, started_rx) = mpsc::channel();
+    let worker = std::thread::spawn({
+        let lock: Java<java::lang::Object> = lock.execute().unwrap();
+        move || {
+            java::lang::Object::new().execute().unwrap();
+            started_tx.send(()).unwrap();
+            lock.wait(200_i64, 0).synchronized(&lock).execute()
+        }
+    });
+
+    // A permanently attached non-daemon thread that exits while the JVM is being destroyed
+    // must still detach, or `DestroyJavaVM` waits for it forever
+    let (attached_tx, attached_rx) = mpsc::channel();
+    let attached = Jvm::spawn("duchess-exits-during-shutdown", move || {
+        attached_tx.send(()).unwrap();
+        release_rx.recv().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    })
+    .unwrap();
+
+    attached_rx.recv().unwrap();
+    started_rx.recv().unwrap();
+    Jvm::shutdown().unwrap();
+    assert!(SHUT_DOWN.load(Ordering::SeqCst));
+    attached.join().unwrap().unwrap();
+
+    // Either the worker's wait finished before the JVM was destroyed, or it never started
+    let result = worker.join().unwrap();
+    assert!(
+        matches!(result, Ok(()) | Err(Error::JvmShutDown)),
+        "{result:?}"
+    );
+
+    let result = java::lang::Object::new().execute();
+    assert!(matches!(result, Err(Error::JvmShutDown)), "{result:?}");
+    assert!(matches!(Jvm::shutdown(), Err(Error::JvmShutDown)));
+
+    // Dropping a reference after shutdown is harmless
+    drop(lock);
+}
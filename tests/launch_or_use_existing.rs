@@ -0,0 +1,35 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Barrier,
+    },
+    thread,
+};
+
+use duchess::{java, prelude::*, Jvm};
+
+static STARTED: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn hooks_run_when_another_thread_launches_the_jvm() {
+    let n = 8;
+    let barrier = Arc::new(Barrier::new(n));
+    thread::scope(|scope| {
+        for _ in 0..n {
+            let barrier = Arc::clone(&barrier);
+            scope.spawn(move || {
+                barrier.wait();
+                // All but one of the threads find the JVM that the winner created
+                Jvm::builder()
+                    .on_jvm_start(|jvm| {
+                        java::lang::Object::new().do_jni(jvm)?;
+                        STARTED.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    })
+                    .launch_or_use_existing()
+                    .unwrap();
+            });
+        }
+    });
+    assert_eq!(STARTED.load(Ordering::SeqCst), n);
+}
@@ -0,0 +1,92 @@
+use std::sync::mpsc;
+
+use duchess::{java, prelude::*, Error, Java, Jvm};
+
+fn new_lock() -> Java<java::lang::Object> {
+    java::lang::Object::new().execute().unwrap()
+}
+
+fn new_ref(lock: &Java<java::lang::Object>) -> Java<java::lang::Object> {
+    lock.execute().unwrap()
+}
+
+#[test]
+fn notify_requires_monitor() {
+    let lock = new_lock();
+
+    let result = lock.notify_all().execute();
+    assert!(matches!(result, Err(Error::Thrown(_))));
+
+    lock.notify_all().synchronized(&lock).execute().unwrap();
+}
+
+#[test]
+fn wait_with_timeout() {
+    let lock = new_lock();
+    lock.wait(10_i64, 0).synchronized(&lock).execute().unwrap();
+}
+
+#[test]
+fn wait_is_woken_by_notify() {
+    let lock = new_lock();
+    let (waiting_tx, waiting_rx) = mpsc::channel();
+
+    let waiter = std::thread::spawn({
+        let lock = new_ref(&lock);
+        move || {
+            // Signal while holding the monitor, so the notify below cannot happen before `wait` releases it
+            WaitOp {
+                lock: &lock,
+                waiting: waiting_tx,
+            }
+            .execute()
+            .unwrap()
+        }
+    });
+
+    waiting_rx.recv().unwrap();
+    lock.notify_all().synchronized(&lock).execute().unwrap();
+    waiter.join().unwrap();
+}
+
+#[test]
+fn monitor_released_on_panic() {
+    let lock = new_lock();
+
+    let result = std::panic::catch_unwind(|| PanicOp.synchronized(&lock).execute());
+    assert!(result.is_err());
+
+    // If the monitor were still held by this thread, another thread could not enter it
+    let lock2 = new_ref(&lock);
+    std::thread::spawn(move || lock2.notify_all().synchronized(&lock2).execute().unwrap())
+        .join()
+        .unwrap();
+}
+
+#[derive(Clone)]
+struct WaitOp<'a> {
+    lock: &'a Java<java::lang::Object>,
+    waiting: mpsc::Sender<()>,
+}
+
+impl JvmOp for WaitOp<'_> {
+    type Output<'jvm> = ();
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> duchess::LocalResult<'jvm, ()> {
+        jvm.synchronized(self.lock, |jvm| {
+            self.waiting.send(()).unwrap();
+            self.lock.wait(0_i64, 0).do_jni(jvm)
+        })
+    }
+}
+
+#[derive(Clone)]
+struct PanicOp;
+
+impl JvmOp for PanicOp {
+    type Output<'jvm> = ();
+
+    fn do_jni<'jvm>(self, _jvm: &mut Jvm<'jvm>) -> duchess::LocalResult<'jvm, ()> {
+        panic!("panic while holding a monitor")
+    }
+}
@@ -0,0 +1,84 @@
+use duchess::{java, prelude::*, Error, Java};
+
+/// An exception caused by another exception, which has no cause and no message.
+fn exception_with_cause() -> Java<java::lang::Throwable> {
+    let outer: Java<java::lang::Throwable> = java::lang::Throwable::new().execute().unwrap();
+    let inner: Java<java::lang::Throwable> = java::lang::Throwable::new().execute().unwrap();
+    let _: Option<Java<java::lang::Throwable>> = outer.init_cause(&inner).execute().unwrap();
+    outer
+}
+
+#[test]
+fn null_in_chain_short_circuits_to_none() {
+    let exception = exception_with_cause();
+
+    // Without `opt`, calling a method on a null intermediate fails
+    let result: Result<Option<String>, _> =
+        exception.get_cause().get_cause().get_message().execute();
+    assert!(matches!(result, Err(Error::NullDeref)), "{result:?}");
+
+    let message: Option<String> = exception
+        .get_cause()
+        .get_cause()
+        .get_message()
+        .opt()
+        .execute()
+        .unwrap();
+    assert_eq!(message, None);
+
+    let hash: Option<i32> = exception
+        .get_cause()
+        .get_cause()
+        .hash_code()
+        .opt()
+        .execute()
+        .unwrap();
+    assert_eq!(hash, None);
+}
+
+#[test]
+fn non_null_chain_produces_some() {
+    let exception = exception_with_cause();
+
+    let cause: Option<Java<java::lang::Throwable>> = exception.get_cause().opt().execute().unwrap();
+    assert!(cause.is_some());
+
+    let hash: Option<i32> = exception.get_cause().hash_code().opt().execute().unwrap();
+    assert!(hash.is_some());
+}
+
+#[test]
+fn exceptions_are_not_turned_into_none() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    let result: Result<Option<String>, _> = list.get(0).opt().execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
+
+#[test]
+fn null_in_argument_chain_short_circuits_the_call() {
+    let exception = exception_with_cause();
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+
+    // A null value passed as an argument reaches Java
+    let added: Option<bool> = list
+        .add(&None::<Java<java::lang::String>>)
+        .opt()
+        .execute()
+        .unwrap();
+    assert_eq!(added, Some(true));
+
+    // A null dereferenced while computing an argument makes the whole call `None`, without calling `add`
+    let result: Result<bool, _> = list
+        .add(exception.get_cause().get_cause().to_string())
+        .execute();
+    assert!(matches!(result, Err(Error::NullDeref)), "{result:?}");
+    let added: Option<bool> = list
+        .add(exception.get_cause().get_cause().to_string())
+        .opt()
+        .execute()
+        .unwrap();
+    assert_eq!(added, None);
+    assert_eq!(list.size().execute().unwrap(), 1);
+}
@@ -0,0 +1,66 @@
+use duchess::{java, Java, JvmOp, ToJava};
+
+#[test]
+fn options_round_trip_through_optional() {
+    let present: Java<java::util::Optional<java::lang::String>> = Some("hello")
+        .to_java::<java::util::Optional<java::lang::String>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert!(present.is_present().execute().unwrap());
+    let back: Option<String> = present.execute().unwrap();
+    assert_eq!(back.as_deref(), Some("hello"));
+
+    let empty: Java<java::util::Optional<java::lang::Integer>> = None::<i32>
+        .to_java::<java::util::Optional<java::lang::Integer>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert!(empty.is_empty().execute().unwrap());
+    let back: Option<i32> = empty.execute().unwrap();
+    assert_eq!(back, None);
+
+    let object: Java<java::lang::Object> = java::lang::Object::new().execute().unwrap();
+    let wrapped: Java<java::util::Optional<java::lang::Object>> = Some(object)
+        .to_java::<java::util::Optional<java::lang::Object>>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let unwrapped: Option<Java<java::lang::Object>> = wrapped.get().execute().unwrap();
+    assert!(unwrapped.is_some());
+}
+
+#[test]
+fn none_still_maps_to_null() {
+    let null: Option<Java<java::lang::String>> = None::<String>
+        .to_java::<java::lang::String>()
+        .execute()
+        .unwrap();
+    assert!(null.is_none());
+}
+
+#[test]
+fn primitive_optionals_round_trip() {
+    let int: Java<java::util::OptionalInt> = Some(42)
+        .to_java::<java::util::OptionalInt>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert_eq!(int.get_as_int().execute().unwrap(), 42);
+    let back: Option<i32> = int.execute().unwrap();
+    assert_eq!(back, Some(42));
+
+    let long: Java<java::util::OptionalLong> = None::<i64>
+        .to_java::<java::util::OptionalLong>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let back: Option<i64> = long.execute().unwrap();
+    assert_eq!(back, None);
+
+    let double: Option<f64> = java::util::OptionalDouble::of(0.5)
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert_eq!(double, Some(0.5));
+}
@@ -0,0 +1,61 @@
+use duchess::{java, prelude::*, Error};
+
+#[derive(Debug)]
+struct Outer(std::io::Error);
+
+impl std::fmt::Display for Outer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "outer failure")
+    }
+}
+
+impl std::error::Error for Outer {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn source_chain_becomes_cause_chain() {
+    let result: Result<String, Box<dyn std::error::Error + Send + Sync>> =
+        Err(Box::new(Outer(std::io::Error::other("inner failure"))));
+
+    let Err(Error::Thrown(exception)) = result
+        .to_java::<java::lang::String>()
+        .execute::<Option<String>>()
+    else {
+        panic!("expected a Java exception");
+    };
+
+    let message: String = exception.get_message().assert_not_null().execute().unwrap();
+    assert_eq!(message, "outer failure");
+
+    let cause: Java<java::lang::Throwable> =
+        exception.get_cause().assert_not_null().execute().unwrap();
+    let message: String = cause.get_message().assert_not_null().execute().unwrap();
+    assert_eq!(message, "inner failure");
+
+    let root: Option<Java<java::lang::Throwable>> = cause.get_cause().execute().unwrap();
+    assert!(root.is_none());
+}
+
+#[test]
+fn thrown_java_exception_is_not_wrapped() {
+    let original: Java<java::lang::RuntimeException> =
+        java::lang::RuntimeException::new().execute().unwrap();
+    let original: Java<java::lang::Throwable> = original.upcast();
+
+    let copy: Java<java::lang::Throwable> = (&original).execute().unwrap();
+    let result: Result<String, Box<dyn std::error::Error + Send + Sync>> =
+        Err(Box::new(Error::Thrown(copy)));
+
+    let Err(Error::Thrown(exception)) = result
+        .to_java::<java::lang::String>()
+        .execute::<Option<String>>()
+    else {
+        panic!("expected a Java exception");
+    };
+
+    let same: bool = exception.equals(&original).execute().unwrap();
+    assert!(same);
+}
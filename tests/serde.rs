@@ -0,0 +1,147 @@
+#![cfg(feature = "serde")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use duchess::{
+    java,
+    prelude::*,
+    serde::{from_java, to_java},
+    Error, Java,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    port: u16,
+    ratio: f64,
+    enabled: bool,
+    initial: char,
+    tags: Vec<String>,
+    limits: BTreeMap<String, i64>,
+    fallback: Option<Box<Config>>,
+    modes: Vec<Mode>,
+    nothing: (),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Off,
+    Fixed(u32),
+    Range(i8, i8),
+    Custom { label: String },
+}
+
+fn config() -> Config {
+    Config {
+        name: "primary".to_string(),
+        port: 8080,
+        ratio: 0.5,
+        enabled: true,
+        initial: 'é',
+        tags: vec!["a".to_string(), "b".to_string()],
+        limits: [("max".to_string(), i64::MAX), ("min".to_string(), -1)].into(),
+        fallback: Some(Box::new(Config {
+            name: "secondary".to_string(),
+            port: 0,
+            ratio: -1.0,
+            enabled: false,
+            initial: '🦀',
+            tags: vec![],
+            limits: BTreeMap::new(),
+            fallback: None,
+            modes: vec![],
+            nothing: (),
+        })),
+        modes: vec![
+            Mode::Off,
+            Mode::Fixed(7),
+            Mode::Range(-1, 1),
+            Mode::Custom {
+                label: "x".to_string(),
+            },
+        ],
+        nothing: (),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let config = config();
+    let java: Option<Java<java::lang::Object>> = to_java(&config).execute().unwrap();
+    let back: Config = from_java(&java).execute().unwrap();
+    assert_eq!(back, config);
+}
+
+#[test]
+fn structs_become_hash_maps() {
+    let java: Java<java::util::HashMap<java::lang::Object, java::lang::Object>> =
+        to_java(&config())
+            .try_downcast::<java::util::HashMap<java::lang::Object, java::lang::Object>>()
+            .execute::<Result<_, Option<Java<java::lang::Object>>>>()
+            .unwrap()
+            .unwrap();
+
+    let name: String = java
+        .get("name")
+        .try_downcast::<java::lang::String>()
+        .execute::<Result<String, Option<Java<java::lang::Object>>>>()
+        .unwrap()
+        .unwrap();
+    assert_eq!(name, "primary");
+
+    let port: Java<java::lang::Integer> = java
+        .get("port")
+        .try_downcast::<java::lang::Integer>()
+        .execute::<Result<_, Option<Java<java::lang::Object>>>>()
+        .unwrap()
+        .unwrap();
+    let port: i32 = port.int_value().execute().unwrap();
+    assert_eq!(port, 8080);
+}
+
+#[test]
+fn java_collections_deserialize() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    list.add("x").execute().unwrap();
+    list.add("y").execute().unwrap();
+    let strings: Vec<String> = from_java(&list).execute().unwrap();
+    assert_eq!(strings, ["x", "y"]);
+
+    let map: Java<java::util::HashMap<java::lang::String, java::lang::Integer>> =
+        java::util::HashMap::new().execute().unwrap();
+    map.put("one", java::lang::Integer::value_of(1))
+        .execute::<Option<Java<java::lang::Integer>>>()
+        .unwrap();
+    let map: HashMap<String, u8> = from_java(&map).execute().unwrap();
+    assert_eq!(map, [("one".to_string(), 1)].into());
+}
+
+#[test]
+fn null_map_values_deserialize_as_none() {
+    let map: Java<java::util::HashMap<java::lang::String, java::lang::Integer>> =
+        java::util::HashMap::new().execute().unwrap();
+    map.put("none", &None::<Java<java::lang::Integer>>)
+        .execute::<Option<Java<java::lang::Integer>>>()
+        .unwrap();
+    let map: HashMap<String, Option<u8>> = from_java(&map).execute().unwrap();
+    assert_eq!(map, [("none".to_string(), None)].into());
+}
+
+#[test]
+fn mismatches_are_errors() {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    list.add("x").execute().unwrap();
+    let result: Result<Vec<i32>, _> = from_java(&list).execute();
+    assert!(matches!(result, Err(Error::Serde(_))), "{result:?}");
+
+    let result: Result<Option<Java<java::lang::Object>>, _> = to_java(&u64::MAX).execute();
+    assert!(matches!(result, Err(Error::Serde(_))), "{result:?}");
+
+    let thread: Option<Java<java::lang::Thread>> =
+        java::lang::Thread::current_thread().execute().unwrap();
+    let result: Result<String, _> = from_java(&thread).execute();
+    assert!(matches!(result, Err(Error::Serde(_))), "{result:?}");
+}
@@ -0,0 +1,70 @@
+use duchess::{java, Error, Java, JvmOp, ToJava};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn system_times_round_trip_through_instants() {
+    for time in [
+        UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+        UNIX_EPOCH - Duration::new(86_400, 1),
+        UNIX_EPOCH,
+    ] {
+        let instant: Java<java::time::Instant> = time
+            .to_java::<java::time::Instant>()
+            .assert_not_null()
+            .execute()
+            .unwrap();
+        let back: SystemTime = instant.execute().unwrap();
+        assert_eq!(back, time);
+    }
+
+    let instant: Java<java::time::Instant> = (UNIX_EPOCH - Duration::new(0, 1))
+        .to_java::<java::time::Instant>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    assert_eq!(instant.get_epoch_second().execute().unwrap(), -1);
+    assert_eq!(instant.get_nano().execute().unwrap(), 999_999_999);
+}
+
+#[test]
+fn durations_round_trip() {
+    let duration = Duration::new(90_061, 5);
+    let java: Java<java::time::Duration> = duration
+        .to_java::<java::time::Duration>()
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let text: String = java.to_string().assert_not_null().execute().unwrap();
+    assert_eq!(text, "PT25H1M1.000000005S");
+    let back: Duration = java.execute().unwrap();
+    assert_eq!(back, duration);
+}
+
+#[test]
+fn out_of_range_values_are_errors() {
+    let result: Result<Option<Java<java::time::Duration>>, _> =
+        Duration::MAX.to_java::<java::time::Duration>().execute();
+    assert!(
+        matches!(
+            result,
+            Err(Error::TimeOutOfRange {
+                target: "java.time.Duration",
+                ..
+            })
+        ),
+        "{result:?}"
+    );
+
+    let negative: Java<java::time::Duration> = java::time::Duration::of_nanos(-1i64)
+        .assert_not_null()
+        .execute()
+        .unwrap();
+    let result: Result<Duration, _> = negative.execute();
+    match result {
+        Err(Error::TimeOutOfRange { value, target }) => {
+            assert_eq!(value, "PT-0.000000001S");
+            assert_eq!(target, "std::time::Duration");
+        }
+        _ => panic!("{result:?}"),
+    }
+}
@@ -0,0 +1,81 @@
+use duchess::{java, prelude::*, Caught2, Error, Java};
+
+fn empty_list() -> Java<java::util::ArrayList<java::lang::String>> {
+    java::util::ArrayList::<java::lang::String>::new()
+        .execute()
+        .unwrap()
+}
+
+#[test]
+fn catch_tuple_matches_first_applicable_type() {
+    let list = empty_list();
+
+    // `get` on an empty list throws `IndexOutOfBoundsException`, which is not an NPE
+    let result = list
+        .get(0)
+        .catch::<(java::lang::NullPointerException, java::lang::RuntimeException)>()
+        .execute::<Result<Option<String>, Caught2<_, _>>>()
+        .unwrap();
+    assert!(matches!(result, Err(Caught2::B(_))), "{result:?}");
+
+    let result = list
+        .get(0)
+        .catch::<(java::lang::RuntimeException, java::lang::Throwable)>()
+        .execute::<Result<Option<String>, Caught2<_, _>>>()
+        .unwrap();
+    assert!(matches!(result, Err(Caught2::A(_))), "{result:?}");
+}
+
+#[test]
+fn catch_tuple_rethrows_unmatched() {
+    let list = empty_list();
+
+    let result = list
+        .get(0)
+        .catch::<(java::lang::NullPointerException, java::lang::NullPointerException)>()
+        .execute::<Result<Option<String>, Caught2<_, _>>>();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
+
+#[test]
+fn catch_all_catches_any_exception() {
+    let list = empty_list();
+
+    let result = list
+        .get(0)
+        .catch_all()
+        .execute::<Result<Option<String>, Java<java::lang::Throwable>>>()
+        .unwrap();
+    assert!(result.is_err());
+
+    let result = list
+        .size()
+        .catch_all()
+        .execute::<Result<i32, Java<java::lang::Throwable>>>()
+        .unwrap();
+    assert_eq!(result.ok(), Some(0));
+}
+
+#[test]
+fn finally_runs_after_success_and_exception() {
+    let list = empty_list();
+
+    let result = list.size().finally(list.add("first")).execute().unwrap();
+    assert_eq!(result, 0);
+
+    let result = list
+        .get(1)
+        .finally(list.add("second"))
+        .execute::<Option<String>>();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+
+    assert_eq!(list.size().execute().unwrap(), 2);
+}
+
+#[test]
+fn finally_exception_replaces_result() {
+    let list = empty_list();
+
+    let result = list.size().finally(list.get(0)).execute();
+    assert!(matches!(result, Err(Error::Thrown(_))), "{result:?}");
+}
@@ -0,0 +1,50 @@
+use duchess::{java, Java, Jvm, JvmOp, Local};
+
+fn list_of_a() -> Java<java::util::ArrayList<java::lang::String>> {
+    let list: Java<java::util::ArrayList<java::lang::String>> =
+        java::util::ArrayList::new().execute().unwrap();
+    list.add("a").execute().unwrap();
+    list
+}
+
+#[test]
+fn upcast_globals_stay_valid() {
+    let list = list_of_a();
+    let upcasts: Vec<Java<java::lang::Object>> = (0..1000)
+        .map(|_| {
+            let copy: Java<java::util::ArrayList<java::lang::String>> = list.execute().unwrap();
+            copy.upcast()
+        })
+        .collect();
+    for object in &upcasts {
+        let text: String = object.to_string().assert_not_null().execute().unwrap();
+        assert_eq!(text, "[a]");
+    }
+}
+
+/// Upcasts many local references to `list` and checks that each one still reads back as `[a]`.
+#[derive(Clone, Copy)]
+struct UpcastLocals<'a>(&'a Java<java::util::ArrayList<java::lang::String>>);
+
+impl JvmOp for UpcastLocals<'_> {
+    type Output<'jvm> = bool;
+
+    fn do_jni<'jvm>(self, jvm: &mut Jvm<'jvm>) -> duchess::LocalResult<'jvm, bool> {
+        let upcasts: Vec<Local<java::lang::Object>> =
+            (0..10).map(|_| jvm.local(&**self.0).upcast()).collect();
+        for object in &upcasts {
+            let text: String = object.to_string().assert_not_null().execute_with(jvm)?;
+            if text != "[a]" {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[test]
+fn upcast_locals_stay_valid() {
+    let list = list_of_a();
+    let all_valid: bool = UpcastLocals(&list).execute().unwrap();
+    assert!(all_valid);
+}
@@ -0,0 +1,54 @@
+use duchess::{java, prelude::*, Java, WeakJava};
+
+fn check_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn weak_refs_are_send_and_sync() {
+    check_send_sync::<WeakJava<java::lang::Object>>();
+}
+
+#[test]
+fn upgrade_while_strongly_reachable() {
+    let list = java::util::ArrayList::<java::lang::String>::new()
+        .execute::<Java<_>>()
+        .unwrap();
+    list.add("hello").execute().unwrap();
+
+    let weak = list.downgrade().unwrap();
+    assert!(!weak.is_cleared().execute().unwrap());
+
+    // Executing a `&WeakJava` upgrades it
+    let upgraded: Option<Java<_>> = (&weak).execute().unwrap();
+    let upgraded = upgraded.expect("object is still reachable");
+    assert_eq!(upgraded.size().execute().unwrap(), 1);
+
+    // Upgraded weak refs can be passed as method arguments
+    let other = java::util::ArrayList::<java::lang::Object>::new()
+        .execute::<Java<_>>()
+        .unwrap();
+    other.add(&weak).execute().unwrap();
+    assert!(other.contains(&list).execute().unwrap());
+}
+
+#[test]
+fn cleared_after_collection() {
+    let weak = java::lang::Object::new()
+        .execute::<Java<_>>()
+        .unwrap()
+        .downgrade()
+        .unwrap();
+
+    let memory = java::lang::management::ManagementFactory::get_memory_mx_bean()
+        .assert_not_null()
+        .execute::<Java<_>>()
+        .unwrap();
+    for _ in 0..10 {
+        if weak.is_cleared().execute().unwrap() {
+            break;
+        }
+        memory.gc().execute().unwrap();
+    }
+
+    assert!(weak.is_cleared().execute().unwrap());
+    assert!((&weak).execute::<Option<Java<_>>>().unwrap().is_none());
+}